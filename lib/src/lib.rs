@@ -1,20 +1,48 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
 use alloy_sol_types::sol;
 
 pub mod rr;
 pub mod serialize;
 
 use ecdsa::signature::Verifier;
+use ed25519_dalek::{
+    Signature as Ed25519Signature, Verifier as Ed25519Verifier, VerifyingKey as Ed25519VerifyingKey,
+};
 use p256::ecdsa::{Signature, VerifyingKey};
+use rsa::{
+    pkcs1v15::{Signature as RsaSignature, VerifyingKey as RsaVerifyingKey},
+    signature::Verifier as RsaVerifier,
+    BigUint, RsaPublicKey,
+};
+use sha2::Sha256;
+
 use rr::{
     dns_class::DNSClass,
-    dnssec::{message::construct_rrset_message_with_sig, rdata::sig::SIG},
+    dnssec::{
+        algorithm::Algorithm,
+        chain::{
+            verify_chain as verify_chain_of_trust,
+            verify_chain_and_rrset as verify_chain_and_rrset_of_trust, ZoneLink,
+        },
+        message::{construct_rrset_message_with_sig, message_tbs},
+        nsec::verify_nsec_denial,
+        rdata::sig::SIG,
+    },
     domain::name::Name,
+    record_type::RecordType,
     resource::Record,
 };
 
 sol! {
     struct PublicValuesStruct {
         bool is_valid;
+        string zone_name;
+        string root_anchor_digest;
     }
 }
 
@@ -38,6 +66,102 @@ pub fn verify_ecdsa_signature(public_key: Vec<u8>, message: Vec<u8>, signature:
     is_valid
 }
 
+/// Verifies a raw Ed25519 signature: a 32-byte compressed public key and a
+/// 64-byte signature over `message`, with no separate digest step since
+/// Ed25519 hashes internally.
+pub fn verify_ed25519_signature(public_key: Vec<u8>, message: Vec<u8>, signature: Vec<u8>) -> bool {
+    let Ok(key_bytes): Result<[u8; 32], _> = public_key.as_slice().try_into() else {
+        return false;
+    };
+
+    let Ok(verifying_key) = Ed25519VerifyingKey::from_bytes(&key_bytes) else {
+        return false;
+    };
+
+    let Ok(sig_bytes): Result<[u8; 64], _> = signature.as_slice().try_into() else {
+        return false;
+    };
+
+    let sig = Ed25519Signature::from_bytes(&sig_bytes);
+
+    verifying_key.verify(message.as_ref(), &sig).is_ok()
+}
+
+/// Parses the RFC 3110 wire format for an RSA public key, `exponent-length |
+/// exponent | modulus`, into `(e, n)`. Returns `None` on any truncated or
+/// malformed encoding rather than panicking.
+fn parse_rsa_public_key(public_key: &[u8]) -> Option<(BigUint, BigUint)> {
+    let (exponent_len, exponent_start) = match public_key.first()? {
+        0 => {
+            let len_bytes = public_key.get(1..3)?;
+            (u16::from_be_bytes([len_bytes[0], len_bytes[1]]) as usize, 3)
+        }
+        &len => (len as usize, 1),
+    };
+
+    let modulus_start = exponent_start + exponent_len;
+    let exponent = BigUint::from_bytes_be(public_key.get(exponent_start..modulus_start)?);
+    let modulus_bytes = public_key.get(modulus_start..).filter(|m| !m.is_empty())?;
+    let modulus = BigUint::from_bytes_be(modulus_bytes);
+
+    Some((exponent, modulus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rsa_public_key_short_exponent_length() {
+        // exponent-length(1) | exponent(3) | modulus(4), the common case
+        // where the exponent (here 65537 = 0x010001) fits in a single
+        // length octet.
+        let key = [3, 0x01, 0x00, 0x01, 0xde, 0xad, 0xbe, 0xef];
+        let (e, n) = parse_rsa_public_key(&key).unwrap();
+
+        assert_eq!(e, BigUint::from(65537u32));
+        assert_eq!(n, BigUint::from_bytes_be(&[0xde, 0xad, 0xbe, 0xef]));
+    }
+
+    #[test]
+    fn parse_rsa_public_key_long_exponent_length() {
+        // A leading zero octet switches to a 2-octet big-endian exponent
+        // length, for exponents that don't fit in a single octet.
+        let key = [0x00, 0x00, 0x03, 0x01, 0x00, 0x01, 0xca, 0xfe];
+        let (e, n) = parse_rsa_public_key(&key).unwrap();
+
+        assert_eq!(e, BigUint::from(65537u32));
+        assert_eq!(n, BigUint::from_bytes_be(&[0xca, 0xfe]));
+    }
+
+    #[test]
+    fn parse_rsa_public_key_truncated_returns_none() {
+        // Declares a 3-byte exponent but supplies none of it.
+        assert!(parse_rsa_public_key(&[3]).is_none());
+        // Declares the long form but is too short to hold the 2-octet length.
+        assert!(parse_rsa_public_key(&[0x00, 0x01]).is_none());
+        assert!(parse_rsa_public_key(&[]).is_none());
+    }
+}
+
+pub fn verify_rsa_signature(public_key: Vec<u8>, message: Vec<u8>, signature: Vec<u8>) -> bool {
+    let Some((exponent, modulus)) = parse_rsa_public_key(&public_key) else {
+        return false;
+    };
+
+    let Ok(rsa_public_key) = RsaPublicKey::new(modulus, exponent) else {
+        return false;
+    };
+
+    let Ok(sig) = RsaSignature::try_from(signature.as_slice()) else {
+        return false;
+    };
+
+    RsaVerifyingKey::<Sha256>::new(rsa_public_key)
+        .verify(message.as_ref(), &sig)
+        .is_ok()
+}
+
 pub fn verify_rrsig(
     public_key: Vec<u8>,
     name: &Name,
@@ -46,7 +170,60 @@ pub fn verify_rrsig(
     records: &[Record],
     signature: Vec<u8>,
 ) -> bool {
-    let message = construct_rrset_message_with_sig(name, dns_class, rrsig, records);
+    let Ok(message) = construct_rrset_message_with_sig(name, dns_class, rrsig, records) else {
+        return false;
+    };
 
-    verify_ecdsa_signature(public_key, message, signature)
+    match rrsig.algorithm() {
+        Algorithm::RSASHA256 => verify_rsa_signature(public_key, message, signature),
+        Algorithm::ECDSAP256SHA256 => verify_ecdsa_signature(public_key, message, signature),
+        Algorithm::ED25519 => verify_ed25519_signature(public_key, message, signature),
+    }
+}
+
+/// Verifies a SIG(0)/TSIG transaction signature covering an entire DNS
+/// message, as opposed to [`verify_rrsig`] which covers a single RRset.
+pub fn verify_message_sig(
+    public_key: Vec<u8>,
+    message_bytes: Vec<u8>,
+    sig: &SIG,
+    signature: Vec<u8>,
+) -> bool {
+    let message = message_tbs(sig, &message_bytes);
+
+    match sig.algorithm() {
+        Algorithm::RSASHA256 => verify_rsa_signature(public_key, message, signature),
+        Algorithm::ECDSAP256SHA256 => verify_ecdsa_signature(public_key, message, signature),
+        Algorithm::ED25519 => verify_ed25519_signature(public_key, message, signature),
+    }
+}
+
+/// Verifies that a signature-validated NSEC/NSEC3 `record` denies the
+/// existence of `name`/`record_type`, for use once [`verify_rrsig`] has
+/// already authenticated the record's owning RRset.
+pub fn verify_denial_of_existence(name: &Name, record_type: RecordType, record: &Record) -> bool {
+    verify_nsec_denial(name, record_type, record)
+}
+
+/// Verifies a full delegation chain from the IANA root anchors down to a
+/// leaf zone, returning the validated zone name on success. See
+/// [`rr::dnssec::chain::verify_chain`] for the per-link checks performed.
+pub fn verify_chain(dns_class: DNSClass, chain: &[ZoneLink]) -> Option<Name> {
+    verify_chain_of_trust(dns_class, chain)
+}
+
+/// Verifies a full delegation chain and binds a leaf RRset to it in one
+/// step, so the leaf's signer key can't be supplied independently of what
+/// the chain actually proved. See
+/// [`rr::dnssec::chain::verify_chain_and_rrset`].
+#[allow(clippy::too_many_arguments)]
+pub fn verify_chain_and_rrset(
+    dns_class: DNSClass,
+    chain: &[ZoneLink],
+    name: &Name,
+    rrsig: &SIG,
+    records: &[Record],
+    signature: Vec<u8>,
+) -> Option<Name> {
+    verify_chain_and_rrset_of_trust(dns_class, chain, name, rrsig, records, signature)
 }
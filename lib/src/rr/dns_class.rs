@@ -1,6 +1,8 @@
+use alloc::string::String;
+
 use serde::{Deserialize, Serialize};
 
-use crate::serialize::binary::{BinEncodable, BinEncoder};
+use crate::serialize::binary::{BinDecodable, BinDecoder, BinEncodable, BinEncoder};
 
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Deserialize, Serialize)]
 #[allow(dead_code)]
@@ -26,6 +28,20 @@ impl BinEncodable for DNSClass {
     }
 }
 
+impl BinDecodable for DNSClass {
+    fn read(decoder: &mut BinDecoder<'_>) -> Result<Self, String> {
+        let value = decoder.read_u16()?;
+        Ok(match value {
+            1 => Self::IN,
+            3 => Self::CH,
+            4 => Self::HS,
+            254 => Self::NONE,
+            255 => Self::ANY,
+            other => Self::OPT(other),
+        })
+    }
+}
+
 impl From<DNSClass> for u16 {
     fn from(rt: DNSClass) -> Self {
         match rt {
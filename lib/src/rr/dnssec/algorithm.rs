@@ -1,12 +1,14 @@
+use alloc::{format, string::String};
 use serde::{Deserialize, Serialize};
 
-use crate::serialize::binary::{BinEncodable, BinEncoder};
+use crate::serialize::binary::{BinDecodable, BinDecoder, BinEncodable, BinEncoder};
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Deserialize, Serialize)]
 pub enum Algorithm {
-    /// For now only support ECDSA P-256 with SHA-256 & RSA with SHA-256
+    /// ECDSA P-256 with SHA-256, RSA with SHA-256, & Ed25519
     ECDSAP256SHA256,
     RSASHA256,
+    ED25519,
 }
 
 impl From<Algorithm> for u8 {
@@ -14,6 +16,7 @@ impl From<Algorithm> for u8 {
         match a {
             Algorithm::RSASHA256 => 8,
             Algorithm::ECDSAP256SHA256 => 13,
+            Algorithm::ED25519 => 15,
         }
     }
 }
@@ -23,3 +26,14 @@ impl BinEncodable for Algorithm {
         encoder.emit(u8::from(*self))
     }
 }
+
+impl BinDecodable for Algorithm {
+    fn read(decoder: &mut BinDecoder<'_>) -> Result<Self, String> {
+        match decoder.read_u8()? {
+            8 => Ok(Self::RSASHA256),
+            13 => Ok(Self::ECDSAP256SHA256),
+            15 => Ok(Self::ED25519),
+            other => Err(format!("unsupported DNSSEC algorithm: {other}")),
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use alloc::{format, string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+use crate::rr::dns_class::DNSClass;
+use crate::rr::dnssec::ds::{compute_ds_digest, verify_ds};
+use crate::rr::dnssec::rdata::dns_key::DNSKEY;
+use crate::rr::dnssec::rdata::ds::DS;
+use crate::rr::dnssec::rdata::sig::SIG;
+use crate::rr::domain::name::Name;
+use crate::rr::record_data::RData;
+use crate::rr::resource::Record;
+use crate::serialize::binary::BinEncodable;
+use crate::verify_rrsig;
+
+/// The IANA root zone's key-signing key (key tag 20326, algorithm 8, SHA-256
+/// digest type 2), published at
+/// <https://data.iana.org/root-anchors/root-anchors.xml>. This is the sole
+/// trust anchor seeding [`verify_chain`]; every other link must prove its
+/// key-signing key back to this digest.
+pub const ROOT_ANCHOR_KEY_TAG: u16 = 20326;
+
+/// SHA-256, per RFC 4509.
+pub const ROOT_ANCHOR_DIGEST_TYPE: u8 = 2;
+
+pub const ROOT_ANCHOR_DIGEST: [u8; 32] = [
+    0xe0, 0x6d, 0x44, 0xb8, 0x0b, 0x8f, 0x1d, 0x39, 0xa9, 0x5c, 0x0b, 0x0d, 0x7c, 0x65, 0xd0, 0x84,
+    0x58, 0xe8, 0x80, 0x40, 0x9b, 0xbc, 0x68, 0x34, 0x57, 0x10, 0x42, 0x37, 0xc7, 0xf8, 0xec, 0x8d,
+];
+
+/// Formats [`ROOT_ANCHOR_DIGEST`] as lowercase hex, for committing alongside
+/// a chain-of-trust proof's public outputs.
+pub fn root_anchor_digest_hex() -> String {
+    let mut hex = String::with_capacity(ROOT_ANCHOR_DIGEST.len() * 2);
+    for byte in ROOT_ANCHOR_DIGEST {
+        hex.push_str(&format!("{byte:02x}"));
+    }
+    hex
+}
+
+/// One hop of a delegation chain: a zone's DNSKEY RRset (as wire-format
+/// `Record`s, so the RRset can be reconstructed and verified the same way as
+/// any other signed RRset), the RRSIG that self-signs that RRset with the
+/// zone's key-signing key, and — for every zone but the root — the DS RRset
+/// published by the parent that authorizes this zone's key-signing key,
+/// together with the RRSIG that proves the *parent* (i.e. the previous link)
+/// actually published it. Without `ds_rrsig`, a DS record is just a claim an
+/// attacker could forge alongside a self-signed DNSKEY RRset; the root anchor
+/// only secures the chain if every DS is itself authenticated against a
+/// link that's already trusted.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct ZoneLink {
+    pub zone_name: Name,
+    pub dnskey_records: Vec<Record>,
+    pub dnskey_rrsig: SIG,
+    pub ds_records: Vec<Record>,
+    pub ds_rrsig: Option<SIG>,
+}
+
+/// Selects the DNSKEY in `dnskey_records` whose RFC 4034 Appendix B key tag
+/// matches `key_tag`.
+///
+/// A 16-bit key tag is cheap to collide (KeyTrap, CVE-2023-50387): a zone can
+/// publish several DNSKEYs that all claim the RRSIG's key tag to force a
+/// naive verifier into retrying the expensive signature check against every
+/// collider. This returns only the *first* matching key and never falls back
+/// to a later one — [`verify_chain`] must hard-fail on that single candidate
+/// rather than loop, so the cost of a colliding zone is bounded to one
+/// signature verification, exactly as for a zone with no collisions at all.
+fn select_ksk(dnskey_records: &[Record], key_tag: u16) -> Option<&DNSKEY> {
+    dnskey_records.iter().find_map(|record| match record.data() {
+        Some(RData::DNSKEY(dnskey)) => {
+            (dnskey.dnskey().calculate_key_tag().ok()? == key_tag).then(|| dnskey.dnskey())
+        }
+        _ => None,
+    })
+}
+
+/// Selects the DS in `ds_records` whose key tag matches `key_tag`, applying
+/// the same bounded first-match discipline as [`select_ksk`] so a colliding
+/// DS RRset can't force repeated digest/signature work.
+fn select_ds(ds_records: &[Record], key_tag: u16) -> Option<&DS> {
+    ds_records.iter().find_map(|record| match record.data() {
+        Some(RData::DS(ds)) => (ds.ds().key_tag() == key_tag).then(|| ds.ds()),
+        _ => None,
+    })
+}
+
+/// Verifies an ordered delegation chain from the IANA root anchors down to a
+/// leaf zone: link 0 must be the root, authorized directly by
+/// [`ROOT_ANCHOR_DIGEST`]; every subsequent link must carry a DS RRset, itself
+/// authenticated by an RRSIG from a key in the *previous* (already-trusted)
+/// link's validated DNSKEY RRset — the parent's zone-signing key, not
+/// necessarily its key-signing key, exactly like any other non-DNSKEY RRset —
+/// whose digest matches this link's key-signing key. Each link's DNSKEY
+/// RRset must also be self-signed by that same key-signing key. Hard-fails —
+/// returning `None` — on the first invalid link rather than trying
+/// alternatives, bounding prover work.
+///
+/// Per link, the (cheap) DS-digest authorization check runs before the
+/// (expensive) RRSIG verifications, so a zone cannot force extra
+/// cryptographic work by publishing keys that fail authorization — see
+/// [`select_ksk`] for the equivalent bound on key-tag collisions.
+///
+/// Returns the validated leaf zone name on success.
+pub fn verify_chain(dns_class: DNSClass, chain: &[ZoneLink]) -> Option<Name> {
+    let mut validated_name = None;
+    let mut prev_dnskey_records: Option<&[Record]> = None;
+
+    for (i, link) in chain.iter().enumerate() {
+        let ksk = select_ksk(&link.dnskey_records, link.dnskey_rrsig.key_tag())?;
+        let ksk_rdata = ksk.to_bytes().ok()?;
+
+        let authorized = if i == 0 {
+            ksk.calculate_key_tag().ok()? == ROOT_ANCHOR_KEY_TAG
+                && compute_ds_digest(&link.zone_name, &ksk_rdata) == ROOT_ANCHOR_DIGEST
+        } else {
+            let parent_records = prev_dnskey_records?;
+            let ds_rrsig = link.ds_rrsig.as_ref()?;
+            let parent_key = select_ksk(parent_records, ds_rrsig.key_tag())?;
+
+            let ds_matches_ksk = select_ds(&link.ds_records, ksk.calculate_key_tag().ok()?)
+                .is_some_and(|ds| verify_ds(ds, &link.zone_name, ksk));
+
+            ds_matches_ksk
+                && verify_rrsig(
+                    parent_key.public_key().to_vec(),
+                    &link.zone_name,
+                    dns_class,
+                    ds_rrsig,
+                    &link.ds_records,
+                    ds_rrsig.sig().to_vec(),
+                )
+        };
+
+        if !authorized {
+            return None;
+        }
+
+        let self_signed = verify_rrsig(
+            ksk.public_key().to_vec(),
+            &link.zone_name,
+            dns_class,
+            &link.dnskey_rrsig,
+            &link.dnskey_records,
+            link.dnskey_rrsig.sig().to_vec(),
+        );
+
+        if !self_signed {
+            return None;
+        }
+
+        prev_dnskey_records = Some(&link.dnskey_records);
+        validated_name = Some(link.zone_name.clone());
+    }
+
+    validated_name
+}
+
+/// Verifies `chain` and then verifies that `rrsig`/`records`/`signature` (an
+/// RRset claimed to live at `name`) were signed by a DNSKEY drawn from the
+/// chain's own validated leaf zone, rather than an independently-supplied
+/// key that has no proven relationship to the chain at all. Returns the
+/// validated zone name on success.
+///
+/// [`verify_chain`] alone only proves that *some* zone's delegation is
+/// intact; it says nothing about whether the RRset a caller wants verified
+/// actually belongs to that zone. Checking `verify_chain(..).is_some()`
+/// alongside an independently-supplied public key lets a valid-but-unrelated
+/// chain vouch for a leaf signed by any attacker-controlled key. This ties
+/// the two together: the leaf name must match the chain's proven zone, and
+/// the leaf signature must verify under a DNSKEY that chain already proved
+/// belongs to that zone.
+pub fn verify_chain_and_rrset(
+    dns_class: DNSClass,
+    chain: &[ZoneLink],
+    name: &Name,
+    rrsig: &SIG,
+    records: &[Record],
+    signature: Vec<u8>,
+) -> Option<Name> {
+    let leaf = chain.last()?;
+    let zone_name = verify_chain(dns_class, chain)?;
+
+    if &zone_name != name {
+        return None;
+    }
+
+    let leaf_key = select_ksk(&leaf.dnskey_records, rrsig.key_tag())?;
+
+    verify_rrsig(
+        leaf_key.public_key().to_vec(),
+        name,
+        dns_class,
+        rrsig,
+        records,
+        signature,
+    )
+    .then_some(zone_name)
+}
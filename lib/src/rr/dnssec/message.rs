@@ -1,7 +1,12 @@
+use alloc::{string::String, vec::Vec};
+
 use crate::rr::dns_class::DNSClass;
+use crate::rr::dnssec::algorithm::Algorithm;
 use crate::rr::dnssec::rdata::sig::SIG;
 use crate::rr::domain::name::Name;
+use crate::rr::record_type::RecordType;
 use crate::rr::resource::Record;
+use crate::rr::rrset::RRset;
 use crate::serialize::binary::{BinEncodable, BinEncoder};
 
 //                      Reconstructing the Signed Data
@@ -60,29 +65,27 @@ use crate::serialize::binary::{BinEncodable, BinEncoder};
 ///
 /// # Return
 ///
-/// * `Vec<u8>` - the to-be-signed serialization of the given record set
+/// * `Result<Vec<u8>, String>` - the to-be-signed serialization of the given
+///   record set, or an error if `sig`'s fields are inconsistent with
+///   `records` (e.g. a Labels field exceeding the owner name's actual label
+///   count)
 pub fn construct_rrset_message_with_sig(
     name: &Name,
     dns_class: DNSClass,
     sig: &SIG,
     records: &[Record],
-) -> Vec<u8> {
-    // TODO: Implement
-
-    // 1. Sort the records
-    let mut rrset: Vec<&Record> = Vec::new();
-
+) -> Result<Vec<u8>, String> {
     let type_covered = sig.type_covered();
 
     // collect only the records for this rrset
-    for record in records {
-        if dns_class == record.dns_class()
-            && type_covered == record.record_type()
-            && name == record.name()
-        {
-            rrset.push(record);
-        }
-    }
+    let rrset_records: Vec<&Record> = records
+        .iter()
+        .filter(|record| {
+            dns_class == record.dns_class()
+                && type_covered == record.record_type()
+                && name == record.name()
+        })
+        .collect();
 
     let num_labels = sig.num_labels();
 
@@ -93,46 +96,45 @@ pub fn construct_rrset_message_with_sig(
     let key_tag = sig.key_tag();
     let signer_name = sig.signer_name();
 
-    let name = determine_name(name, num_labels).unwrap();
+    let name = determine_name(name, num_labels)?;
 
     let mut buf: Vec<u8> = Vec::new();
     let mut encoder: BinEncoder<'_> = BinEncoder::new(&mut buf);
 
     encoder.set_canonical_names(true);
-    type_covered.emit(&mut encoder).unwrap();
-    algorithm.emit(&mut encoder).unwrap();
-    encoder.emit(num_labels).unwrap();
-    encoder.emit_u32(original_ttl).unwrap();
-    encoder.emit_u32(sig_expiration).unwrap();
-    encoder.emit_u32(sig_inception).unwrap();
-    encoder.emit_u16(key_tag).unwrap();
-    signer_name.emit_as_canonical(&mut encoder, true).unwrap();
-
-    // Place RRSets
-    for record in rrset {
-        name.to_lowercase()
-            .emit_as_canonical(&mut encoder, true)
-            .unwrap();
-
-        type_covered.emit(&mut encoder).unwrap();
-        dns_class.emit(&mut encoder).unwrap();
-        encoder.emit_u32(original_ttl).unwrap();
-
-        let mut rdata_buf: Vec<u8> = Vec::new();
-
-        {
-            let mut rdata_encoder = BinEncoder::new(&mut rdata_buf);
-            rdata_encoder.set_canonical_names(true);
-            if let Some(rdata) = record.data() {
-                assert!(rdata.emit(&mut rdata_encoder).is_ok());
-            }
-        }
+    emit_pre_sig(
+        &mut encoder,
+        type_covered,
+        algorithm,
+        num_labels,
+        original_ttl,
+        sig_expiration,
+        sig_inception,
+        key_tag,
+        signer_name,
+    )?;
+
+    // RFC 4034 §6.3: the RRs are placed in canonical order, with the RRSIG's
+    // signer-reconstructed name substituted for each record's own (this
+    // matters for wildcard expansion) and the TTL replaced by the RRSIG's
+    // Original TTL. `RRset` owns the canonical sort and serialization; see
+    // [`RRset::to_canonical_signed_bytes`].
+    if !rrset_records.is_empty() {
+        let renamed_records: Vec<Record> = rrset_records
+            .into_iter()
+            .map(|record| {
+                let mut record = record.clone();
+                record.name_labels = name.clone();
+                record
+            })
+            .collect();
 
-        encoder.emit_u16(rdata_buf.len() as u16).unwrap();
-        encoder.emit_vec(&rdata_buf).unwrap();
+        let rrset = RRset::new(renamed_records)?;
+        let rrset_bytes = rrset.to_canonical_signed_bytes(original_ttl)?;
+        encoder.emit_vec(&rrset_bytes)?;
     }
 
-    buf
+    Ok(buf)
 }
 
 pub fn determine_name(name: &Name, num_labels: u8) -> Result<Name, String> {
@@ -170,3 +172,101 @@ pub fn determine_name(name: &Name, num_labels: u8) -> Result<Name, String> {
 
     Err("could not determine name".into())
 }
+
+/// Emits the RRSIG/SIG RDATA fields that precede the Signature field, with
+/// the signer's name in canonical (lowercased, uncompressed) form.
+///
+/// This is the common prefix shared by the RRset TBS built by
+/// [`construct_rrset_message_with_sig`] and the message-level TBS built by
+/// [`message_tbs`].
+#[allow(clippy::too_many_arguments)]
+pub fn emit_pre_sig(
+    encoder: &mut BinEncoder<'_>,
+    type_covered: RecordType,
+    algorithm: Algorithm,
+    num_labels: u8,
+    original_ttl: u32,
+    sig_expiration: u32,
+    sig_inception: u32,
+    key_tag: u16,
+    signer_name: &Name,
+) -> Result<(), String> {
+    type_covered.emit(encoder)?;
+    algorithm.emit(encoder)?;
+    encoder.emit(num_labels)?;
+    encoder.emit_u32(original_ttl)?;
+    encoder.emit_u32(sig_expiration)?;
+    encoder.emit_u32(sig_inception)?;
+    encoder.emit_u16(key_tag)?;
+    signer_name.to_lowercase().emit_as_canonical(encoder, true)
+}
+
+/// Returns the to-be-signed serialization of a SIG(0)/TSIG transaction
+/// signature: the pre-signature RDATA fields of `sig` (see [`emit_pre_sig`])
+/// followed by the raw bytes of the DNS message the signature covers.
+///
+/// Unlike [`construct_rrset_message_with_sig`], this does not reconstruct or
+/// sort any RRset; `message_bytes` is the exact wire-format message as sent.
+pub fn message_tbs(sig: &SIG, message_bytes: &[u8]) -> Vec<u8> {
+    let mut buf: Vec<u8> = Vec::new();
+    let mut encoder: BinEncoder<'_> = BinEncoder::new(&mut buf);
+
+    encoder.set_canonical_names(true);
+    emit_pre_sig(
+        &mut encoder,
+        sig.type_covered(),
+        sig.algorithm(),
+        sig.num_labels(),
+        sig.original_ttl(),
+        sig.sig_expiration(),
+        sig.sig_inception(),
+        sig.key_tag(),
+        sig.signer_name(),
+    )
+    .unwrap();
+
+    encoder.emit_vec(message_bytes).unwrap();
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn determine_name_returns_fqdn_when_labels_match() {
+        let name = Name::from_ascii("www.example.com.").unwrap();
+        let determined = determine_name(&name, name.num_labels()).unwrap();
+
+        assert_eq!(determined, name);
+    }
+
+    #[test]
+    fn determine_name_reconstructs_wildcard_owner() {
+        // A response synthesized from a wildcard: the RRSIG's Labels field
+        // (2, for "example.com") is less than the expanded owner's actual
+        // label count (3, for "www.example.com"), so the name to verify
+        // against is reconstructed as "*.example.com.".
+        let fqdn = Name::from_ascii("www.example.com.").unwrap();
+        let determined = determine_name(&fqdn, 2).unwrap();
+
+        assert_eq!(determined, Name::from_ascii("*.example.com.").unwrap());
+    }
+
+    #[test]
+    fn determine_name_wildcard_at_zone_apex() {
+        // Labels = 0 reconstructs down to just the wildcard label itself,
+        // with no remaining non-root labels to append.
+        let fqdn = Name::from_ascii("example.com.").unwrap();
+        let determined = determine_name(&fqdn, 0).unwrap();
+
+        assert_eq!(determined, Name::from_ascii("*.").unwrap());
+    }
+
+    #[test]
+    fn determine_name_errs_when_labels_field_exceeds_owner() {
+        let fqdn = Name::from_ascii("example.com.").unwrap();
+        assert!(determine_name(&fqdn, fqdn.num_labels() + 1).is_err());
+    }
+}
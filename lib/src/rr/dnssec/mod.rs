@@ -0,0 +1,6 @@
+pub mod algorithm;
+pub mod chain;
+pub mod ds;
+pub mod message;
+pub mod nsec;
+pub mod rdata;
@@ -0,0 +1,235 @@
+use alloc::{string::String, vec::Vec};
+use core::cmp::Ordering;
+
+use sha1::{Digest, Sha1};
+
+use crate::rr::dnssec::rdata::nsec::NSEC;
+use crate::rr::dnssec::rdata::nsec3::NSEC3;
+use crate::rr::domain::name::{Name, NameComparison};
+use crate::rr::record_type::RecordType;
+use crate::rr::resource::Record;
+use crate::rr::record_data::RData;
+use crate::serialize::binary::{BinEncodable, BinEncoder};
+
+/// Checks whether `type_bit_maps` (RFC 4034 §4.1.2 wire format: a sequence of
+/// `window | bitmap_length | bitmap` blocks) asserts the presence of
+/// `record_type`.
+fn bitmap_asserts_type(type_bit_maps: &[u8], record_type: RecordType) -> bool {
+    let type_code: u16 = record_type.into();
+    let window = (type_code / 256) as u8;
+    let bit = (type_code % 256) as usize;
+
+    let mut pos = 0;
+    while pos + 2 <= type_bit_maps.len() {
+        let block_window = type_bit_maps[pos];
+        let bitmap_len = type_bit_maps[pos + 1] as usize;
+        let bitmap_start = pos + 2;
+        let bitmap_end = bitmap_start + bitmap_len;
+        if bitmap_end > type_bit_maps.len() {
+            return false;
+        }
+
+        if block_window == window {
+            let byte_idx = bit / 8;
+            if byte_idx >= bitmap_len {
+                return false;
+            }
+            let bit_mask = 0b1000_0000 >> (bit % 8);
+            return type_bit_maps[bitmap_start + byte_idx] & bit_mask != 0;
+        }
+
+        pos = bitmap_end;
+    }
+
+    false
+}
+
+/// Compares two names in RFC 4034 §6.1 canonical order, treating a name that
+/// is a strict prefix of the other (i.e. an ancestor zone, with fewer
+/// labels) as sorting first — see [`Name::canonical_cmp`].
+fn canonical_name_cmp(a: &Name, b: &Name) -> Ordering {
+    match a.canonical_cmp(b) {
+        NameComparison::Less | NameComparison::Shorter => Ordering::Less,
+        NameComparison::Greater | NameComparison::Longer => Ordering::Greater,
+        NameComparison::Equal => Ordering::Equal,
+    }
+}
+
+/// Returns true if `name` falls strictly between `owner` and `next` in
+/// canonical order, accounting for the wrap-around NSEC/NSEC3 record at the
+/// end of a zone (whose "next" points back to the zone apex).
+fn in_gap<T: Ord>(owner: &T, next: &T, candidate: &T) -> bool {
+    match owner.cmp(next) {
+        Ordering::Less => candidate > owner && candidate < next,
+        _ => candidate > owner || candidate < next,
+    }
+}
+
+/// Decodes an RFC 5155 base32hex (no padding) string into raw bytes, as used
+/// for the leftmost label of an NSEC3 owner name.
+fn base32hex_decode(input: &[u8]) -> Result<Vec<u8>, String> {
+    const ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+
+    let mut bits: u64 = 0;
+    let mut bit_count: u32 = 0;
+    let mut out = Vec::new();
+
+    for &c in input {
+        let c = c.to_ascii_uppercase();
+        let val = ALPHABET
+            .iter()
+            .position(|&a| a == c)
+            .ok_or("invalid base32hex character in NSEC3 owner name")? as u64;
+
+        bits = (bits << 5) | val;
+        bit_count += 5;
+
+        if bit_count >= 8 {
+            bit_count -= 8;
+            out.push(((bits >> bit_count) & 0xFF) as u8);
+        }
+    }
+
+    Ok(out)
+}
+
+/// Computes the RFC 5155 §5 iterated hash of `name` with the given algorithm,
+/// salt, and iteration count. Only hash algorithm 1 (SHA-1) is defined.
+fn nsec3_hash(name: &Name, hash_algorithm: u8, iterations: u16, salt: &[u8]) -> Result<Vec<u8>, String> {
+    if hash_algorithm != 1 {
+        return Err("unsupported NSEC3 hash algorithm".into());
+    }
+
+    let mut wire_name: Vec<u8> = Vec::new();
+    let mut encoder = BinEncoder::new(&mut wire_name);
+    encoder.set_canonical_names(true);
+    name.to_lowercase().emit_as_canonical(&mut encoder, true)?;
+    drop(encoder);
+
+    let mut digest = wire_name;
+    for _ in 0..=iterations {
+        let mut hasher = Sha1::new();
+        hasher.update(&digest);
+        hasher.update(salt);
+        digest = hasher.finalize().to_vec();
+    }
+
+    Ok(digest)
+}
+
+/// Checks that `nsec`, owned by `owner`, proves that `name`/`record_type`
+/// does not exist: `name` must fall strictly between `owner` and the NSEC's
+/// next domain name, and the type bit map must not assert `record_type`.
+fn verify_nsec(name: &Name, record_type: RecordType, owner: &Name, nsec: &NSEC) -> bool {
+    let next = nsec.next_domain_name();
+
+    let gap = match canonical_name_cmp(owner, next) {
+        Ordering::Less => {
+            canonical_name_cmp(owner, name) == Ordering::Less
+                && canonical_name_cmp(name, next) == Ordering::Less
+        }
+        _ => {
+            canonical_name_cmp(name, owner) == Ordering::Greater
+                || canonical_name_cmp(name, next) == Ordering::Less
+        }
+    };
+
+    gap && !bitmap_asserts_type(nsec.type_bit_maps(), record_type)
+}
+
+/// Checks that `nsec3`, owned by `owner`, proves that `name`/`record_type`
+/// does not exist: the iterated hash of `name` must fall strictly between
+/// the owner's hash (the base32hex-decoded leftmost label of `owner`) and the
+/// NSEC3's next hashed owner name, and the type bit map must not assert
+/// `record_type`.
+fn verify_nsec3(name: &Name, record_type: RecordType, owner: &Name, nsec3: &NSEC3) -> bool {
+    let Some(owner_hash_label) = owner.iter().next() else {
+        return false;
+    };
+    let Ok(owner_hash) = base32hex_decode(owner_hash_label) else {
+        return false;
+    };
+    let Ok(candidate_hash) = nsec3_hash(
+        name,
+        nsec3.hash_algorithm(),
+        nsec3.iterations(),
+        nsec3.salt(),
+    ) else {
+        return false;
+    };
+
+    let gap = in_gap(
+        &owner_hash,
+        &nsec3.next_hashed_owner_name().to_vec(),
+        &candidate_hash,
+    );
+
+    gap && !bitmap_asserts_type(nsec3.type_bit_maps(), record_type)
+}
+
+/// Verifies that a signature-validated NSEC or NSEC3 record denies the
+/// existence of `name`/`record_type`.
+pub fn verify_nsec_denial(name: &Name, record_type: RecordType, record: &Record) -> bool {
+    match record.data() {
+        Some(RData::NSEC(nsec)) => verify_nsec(name, record_type, record.name(), nsec.nsec()),
+        Some(RData::NSEC3(nsec3)) => {
+            verify_nsec3(name, record_type, record.name(), nsec3.nsec3())
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base32hex_decode_round_trips_all_alphabet_values() {
+        // Each base32hex digit 0-31, five bits apiece, packed into 20
+        // octets with no padding needed (160 bits / 8 = 20 bytes exactly).
+        let input = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+        let decoded = base32hex_decode(input).unwrap();
+
+        assert_eq!(decoded.len(), 20);
+
+        // Decoding is case-insensitive.
+        let lower = b"0123456789abcdefghijklmnopqrstuv";
+        assert_eq!(base32hex_decode(lower).unwrap(), decoded);
+    }
+
+    #[test]
+    fn base32hex_decode_rejects_invalid_character() {
+        assert!(base32hex_decode(b"0000WWWW").is_err());
+    }
+
+    #[test]
+    fn nsec3_hash_rejects_unsupported_algorithm() {
+        let name = Name::from_ascii("example.com.").unwrap();
+        assert!(nsec3_hash(&name, 0, 1, &[]).is_err());
+    }
+
+    #[test]
+    fn nsec3_hash_is_deterministic_and_salt_and_iteration_sensitive() {
+        let name = Name::from_ascii("example.com.").unwrap();
+
+        let once = nsec3_hash(&name, 1, 0, &[]).unwrap();
+        let again = nsec3_hash(&name, 1, 0, &[]).unwrap();
+        assert_eq!(once, again, "hashing is deterministic");
+        assert_eq!(once.len(), 20, "SHA-1 output is 20 octets");
+
+        let salted = nsec3_hash(&name, 1, 0, &[0xab, 0xcd]).unwrap();
+        assert_ne!(once, salted, "salt changes the hash");
+
+        let iterated = nsec3_hash(&name, 1, 1, &[]).unwrap();
+        assert_ne!(once, iterated, "iteration count changes the hash");
+    }
+
+    #[test]
+    fn in_gap_handles_wraparound_at_zone_end() {
+        // The last NSEC3 in a zone wraps its "next hashed owner name" back
+        // to the zone apex, so `next < owner` and the gap spans the wrap.
+        assert!(in_gap(&10u8, &2u8, &20u8));
+        assert!(in_gap(&10u8, &2u8, &1u8));
+        assert!(!in_gap(&10u8, &2u8, &5u8));
+    }
+}
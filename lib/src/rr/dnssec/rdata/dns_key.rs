@@ -1,17 +1,18 @@
+use alloc::{string::String, vec::Vec};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    rr::dnssec::algorithm::Algorithm,
-    serialize::binary::{BinEncodable, BinEncoder},
+    rr::dnssec::{algorithm::Algorithm, ds::compute_key_tag},
+    serialize::binary::{BinDecodable, BinDecoder, BinEncodable, BinEncoder},
 };
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub struct DNSKEY {
-    zone_key: bool,
-    secure_entry_point: bool,
-    revoke: bool,
-    algorithm: Algorithm,
-    public_key: Vec<u8>,
+    pub zone_key: bool,
+    pub secure_entry_point: bool,
+    pub revoke: bool,
+    pub algorithm: Algorithm,
+    pub public_key: Vec<u8>,
 }
 
 impl DNSKEY {
@@ -49,6 +50,16 @@ impl DNSKEY {
     pub fn public_key(&self) -> &[u8] {
         &self.public_key
     }
+
+    /// [RFC 4034](https://tools.ietf.org/html/rfc4034#appendix-b), the
+    /// Appendix B key tag checksum, computed over this DNSKEY's own RDATA
+    /// (flags, protocol, algorithm, public key). Lets a verifier confirm a
+    /// candidate key actually matches an RRSIG's `key_tag` field rather than
+    /// trusting that it was pre-selected correctly.
+    pub fn calculate_key_tag(&self) -> Result<u16, String> {
+        let rdata = self.to_bytes()?;
+        Ok(compute_key_tag(&rdata))
+    }
 }
 
 impl BinEncodable for DNSKEY {
@@ -61,3 +72,28 @@ impl BinEncodable for DNSKEY {
         Ok(())
     }
 }
+
+impl DNSKEY {
+    /// Reads the flags/protocol/algorithm header plus the remaining
+    /// `rdata_length` octets of public key.
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, rdata_length: usize) -> Result<Self, String> {
+        const HEADER_LEN: usize = 4; // flags(2) + protocol(1) + algorithm(1)
+
+        if rdata_length < HEADER_LEN {
+            return Err("DNSKEY RDATA too short for its fixed header".into());
+        }
+
+        let flags = decoder.read_u16()?;
+        let _protocol = decoder.read_u8()?;
+        let algorithm = Algorithm::read(decoder)?;
+        let public_key = decoder.read_vec(rdata_length - HEADER_LEN)?;
+
+        Ok(Self {
+            zone_key: flags & 0b0000_0001_0000_0000 != 0,
+            secure_entry_point: flags & 0b0000_0000_0000_0001 != 0,
+            revoke: flags & 0b0000_0000_1000_0000 != 0,
+            algorithm,
+            public_key,
+        })
+    }
+}
@@ -0,0 +1,77 @@
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    rr::dnssec::algorithm::Algorithm,
+    serialize::binary::{BinDecodable, BinDecoder, BinEncodable, BinEncoder},
+};
+
+/// [RFC 4034](https://tools.ietf.org/html/rfc4034#section-5), DNSSEC Resource Records, March 2005
+///
+/// ```text
+/// 5.1.  DS RDATA Wire Format
+///
+///    The RDATA for a DS RR consists of a 2 octet Key Tag field, a 1
+///    octet Algorithm field, a 1 octet Digest Type field, and a Digest
+///    field.
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct DS {
+    pub key_tag: u16,
+    pub algorithm: Algorithm,
+    pub digest_type: u8,
+    pub digest: Vec<u8>,
+}
+
+impl DS {
+    pub fn key_tag(&self) -> u16 {
+        self.key_tag
+    }
+
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    pub fn digest_type(&self) -> u8 {
+        self.digest_type
+    }
+
+    pub fn digest(&self) -> &[u8] {
+        &self.digest
+    }
+}
+
+impl BinEncodable for DS {
+    fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
+        encoder.emit_u16(self.key_tag())?;
+        self.algorithm().emit(encoder)?;
+        encoder.emit(self.digest_type())?;
+        encoder.emit_vec(self.digest())?;
+
+        Ok(())
+    }
+}
+
+impl DS {
+    /// Reads the key tag/algorithm/digest type header plus the remaining
+    /// `rdata_length` octets of digest.
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, rdata_length: usize) -> Result<Self, String> {
+        const HEADER_LEN: usize = 4; // key_tag(2) + algorithm(1) + digest_type(1)
+
+        if rdata_length < HEADER_LEN {
+            return Err("DS RDATA too short for its fixed header".into());
+        }
+
+        let key_tag = decoder.read_u16()?;
+        let algorithm = Algorithm::read(decoder)?;
+        let digest_type = decoder.read_u8()?;
+        let digest = decoder.read_vec(rdata_length - HEADER_LEN)?;
+
+        Ok(Self {
+            key_tag,
+            algorithm,
+            digest_type,
+            digest,
+        })
+    }
+}
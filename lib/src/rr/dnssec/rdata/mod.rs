@@ -1,6 +1,10 @@
+use alloc::string::String;
 use serde::{Deserialize, Serialize};
 
 use dns_key::DNSKEY;
+use ds::DS;
+use nsec::NSEC;
+use nsec3::NSEC3;
 use rrsig::RRSIG;
 use sig::SIG;
 
@@ -9,6 +13,9 @@ use crate::serialize::binary::{BinEncodable, BinEncoder};
 #[derive(Debug, PartialEq, Clone, Eq, Serialize, Deserialize)]
 pub enum DNSSECRData {
     DNSKEY(DNSKEY),
+    DS(DS),
+    NSEC(NSEC),
+    NSEC3(NSEC3),
     RRSIG(RRSIG),
     SIG(SIG),
 }
@@ -19,6 +26,9 @@ impl DNSSECRData {
             Self::DNSKEY(ref dnskey) => {
                 encoder.with_canonical_names(|encoder| dnskey.emit(encoder))
             }
+            Self::DS(ref ds) => encoder.with_canonical_names(|encoder| ds.emit(encoder)),
+            Self::NSEC(ref nsec) => encoder.with_canonical_names(|encoder| nsec.emit(encoder)),
+            Self::NSEC3(ref nsec3) => encoder.with_canonical_names(|encoder| nsec3.emit(encoder)),
             Self::RRSIG(ref rrsig) => encoder.with_canonical_names(|encoder| rrsig.emit(encoder)),
             Self::SIG(ref sig) => encoder.with_canonical_names(|encoder| sig.emit(encoder)),
         }
@@ -26,5 +36,8 @@ impl DNSSECRData {
 }
 
 pub mod dns_key;
+pub mod ds;
+pub mod nsec;
+pub mod nsec3;
 pub mod rrsig;
 pub mod sig;
@@ -0,0 +1,71 @@
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    rr::domain::name::Name,
+    serialize::binary::{BinDecodable, BinDecoder, BinEncodable, BinEncoder},
+};
+
+/// [RFC 4034](https://tools.ietf.org/html/rfc4034#section-4), DNSSEC Resource Records, March 2005
+///
+/// ```text
+/// 4.1.  NSEC RDATA Wire Format
+///
+///    The RDATA of the NSEC RR is as shown below:
+///
+///                         1 1 1 1 1 1 1 1 1 1 2 2 2 2 2 2 2 2 2 2 3 3
+///     0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+///    +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+///    /                      Next Domain Name                       /
+///    +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+///    /                       Type Bit Maps                         /
+///    +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct NSEC {
+    next_domain_name: Name,
+    type_bit_maps: Vec<u8>,
+}
+
+impl NSEC {
+    pub fn next_domain_name(&self) -> &Name {
+        &self.next_domain_name
+    }
+
+    pub fn type_bit_maps(&self) -> &[u8] {
+        &self.type_bit_maps
+    }
+}
+
+impl BinEncodable for NSEC {
+    fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
+        let is_canonical_names = encoder.is_canonical_names();
+        self.next_domain_name()
+            .emit_with_lowercase(encoder, is_canonical_names)?;
+        encoder.emit_vec(self.type_bit_maps())?;
+
+        Ok(())
+    }
+}
+
+impl NSEC {
+    /// Reads the next domain name followed by the remaining `rdata_length`
+    /// octets as the type bit maps.
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, rdata_length: usize) -> Result<Self, String> {
+        let rdata_start = decoder.index();
+
+        let next_domain_name = Name::read(decoder)?;
+
+        let consumed = decoder.index() - rdata_start;
+        if consumed > rdata_length {
+            return Err("NSEC RDATA too short for its next domain name".into());
+        }
+
+        let type_bit_maps = decoder.read_vec(rdata_length - consumed)?;
+
+        Ok(Self {
+            next_domain_name,
+            type_bit_maps,
+        })
+    }
+}
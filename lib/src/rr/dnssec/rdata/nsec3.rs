@@ -0,0 +1,111 @@
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+use crate::serialize::binary::{BinDecodable, BinDecoder, BinEncodable, BinEncoder};
+
+/// [RFC 5155](https://tools.ietf.org/html/rfc5155#section-3), DNSSEC Hashed Authenticated Denial of Existence, March 2008
+///
+/// ```text
+/// 3.2.  NSEC3 RDATA Wire Format
+///
+///     0                   1                   2                   3
+///     0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+///    +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+///    |   Hash Alg.   |     Flags     |          Iterations          |
+///    +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+///    |  Salt Length  |                     Salt                     /
+///    +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+///    |  Hash Length  |             Next Hashed Owner Name           /
+///    +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+///    /                         Type Bit Maps                        /
+///    +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct NSEC3 {
+    hash_algorithm: u8,
+    flags: u8,
+    iterations: u16,
+    salt: Vec<u8>,
+    next_hashed_owner_name: Vec<u8>,
+    type_bit_maps: Vec<u8>,
+}
+
+impl NSEC3 {
+    pub fn hash_algorithm(&self) -> u8 {
+        self.hash_algorithm
+    }
+
+    pub fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    pub fn iterations(&self) -> u16 {
+        self.iterations
+    }
+
+    pub fn salt(&self) -> &[u8] {
+        &self.salt
+    }
+
+    pub fn next_hashed_owner_name(&self) -> &[u8] {
+        &self.next_hashed_owner_name
+    }
+
+    pub fn type_bit_maps(&self) -> &[u8] {
+        &self.type_bit_maps
+    }
+}
+
+impl BinEncodable for NSEC3 {
+    fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
+        encoder.emit(self.hash_algorithm())?;
+        encoder.emit(self.flags())?;
+        encoder.emit_u16(self.iterations())?;
+        encoder.emit(self.salt().len() as u8)?;
+        encoder.emit_vec(self.salt())?;
+        encoder.emit(self.next_hashed_owner_name().len() as u8)?;
+        encoder.emit_vec(self.next_hashed_owner_name())?;
+        encoder.emit_vec(self.type_bit_maps())?;
+
+        Ok(())
+    }
+}
+
+impl NSEC3 {
+    /// Reads the fixed hash-algorithm/flags/iterations header, the
+    /// length-prefixed salt, the length-prefixed next hashed owner name, and
+    /// the remaining `rdata_length` octets as the type bit maps.
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, rdata_length: usize) -> Result<Self, String> {
+        let rdata_start = decoder.index();
+
+        const HEADER_LEN: usize = 5; // hash_algorithm(1) + flags(1) + iterations(2) + salt_length(1)
+        if rdata_length < HEADER_LEN {
+            return Err("NSEC3 RDATA too short for its fixed header".into());
+        }
+
+        let hash_algorithm = decoder.read_u8()?;
+        let flags = decoder.read_u8()?;
+        let iterations = decoder.read_u16()?;
+        let salt_length = decoder.read_u8()? as usize;
+        let salt = decoder.read_vec(salt_length)?;
+
+        let hash_length = decoder.read_u8()? as usize;
+        let next_hashed_owner_name = decoder.read_vec(hash_length)?;
+
+        let consumed = decoder.index() - rdata_start;
+        if consumed > rdata_length {
+            return Err("NSEC3 RDATA too short for its salt and hashed owner name".into());
+        }
+
+        let type_bit_maps = decoder.read_vec(rdata_length - consumed)?;
+
+        Ok(Self {
+            hash_algorithm,
+            flags,
+            iterations,
+            salt,
+            next_hashed_owner_name,
+            type_bit_maps,
+        })
+    }
+}
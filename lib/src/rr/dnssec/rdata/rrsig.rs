@@ -1,3 +1,5 @@
+use alloc::string::String;
+
 use serde::{Deserialize, Serialize};
 
 use crate::serialize::binary::{BinEncodable, BinEncoder};
@@ -1,9 +1,10 @@
+use alloc::{string::String, vec::Vec};
 use serde::{Deserialize, Serialize};
 
 use crate::rr::dnssec::algorithm::Algorithm;
 use crate::rr::domain::name::Name;
 use crate::rr::record_type::RecordType;
-use crate::serialize::binary::{BinEncodable, BinEncoder};
+use crate::serialize::binary::{BinDecodable, BinDecoder, BinEncodable, BinEncoder};
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize, Serialize)]
 pub struct SIG {
@@ -214,3 +215,40 @@ impl BinEncodable for SIG {
         Ok(())
     }
 }
+
+impl SIG {
+    /// Reads the fixed 18-octet SIG/RRSIG header, the (possibly compressed)
+    /// signer's name, and the remaining `rdata_length` octets as the
+    /// signature.
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, rdata_length: usize) -> Result<Self, String> {
+        let rdata_start = decoder.index();
+
+        let type_covered = RecordType::read(decoder)?;
+        let algorithm = Algorithm::read(decoder)?;
+        let num_labels = decoder.read_u8()?;
+        let original_ttl = decoder.read_u32()?;
+        let sig_expiration = decoder.read_u32()?;
+        let sig_inception = decoder.read_u32()?;
+        let key_tag = decoder.read_u16()?;
+        let signer_name = Name::read(decoder)?;
+
+        let consumed = decoder.index() - rdata_start;
+        if consumed > rdata_length {
+            return Err("SIG/RRSIG RDATA too short for its fixed header".into());
+        }
+
+        let sig = decoder.read_vec(rdata_length - consumed)?;
+
+        Ok(Self {
+            type_covered,
+            algorithm,
+            num_labels,
+            original_ttl,
+            sig_expiration,
+            sig_inception,
+            key_tag,
+            signer_name,
+            sig,
+        })
+    }
+}
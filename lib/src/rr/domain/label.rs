@@ -1,10 +1,62 @@
+use alloc::{format, string::String, vec::Vec};
+use core::borrow::Borrow;
+use core::fmt::{self, Write};
+use core::hash::{Hash, Hasher};
+
 use serde::{Deserialize, Serialize};
-use std::borrow::Borrow;
-use std::fmt::{self, Write};
-use std::hash::{Hash, Hasher};
 
 const WILDCARD: &[u8] = b"*";
 
+/// Which characters [`Label::from_ascii_with_profile`] accepts, and how
+/// strictly.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AllowedAscii {
+    /// The crate's historical policy: alphanumerics, `-` (not leading),
+    /// `_` (for SRV-like labels), a leading `*` (wildcard), and `.` when not
+    /// encoding for the wire. Permissive enough to accept labels that could
+    /// never appear in a real signed zone.
+    Permissive,
+    /// RFC 1123 §2.1 hostname syntax: only letters, digits, and `-`, which
+    /// may not lead or trail a label; the top-level label is additionally
+    /// required to be either all-alphabetic or a valid `xn--` IDNA A-label.
+    Rfc1123,
+}
+
+/// A typed alternative to the crate's usual `Result<_, String>` for the ways
+/// a label can fail [`Label::from_raw_bytes`] or an [`AllowedAscii`]
+/// profile. Converts to `String` via [`From`] so it still works with `?` in
+/// code that expects the crate's usual string errors.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum LabelError {
+    EmptyLabel,
+    LabelTooLong,
+    DisallowedChar(char),
+    LabelStartsWithHyphen,
+    LabelEndsWithHyphen,
+    InvalidTld,
+}
+
+impl fmt::Display for LabelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyLabel => write!(f, "Label requires a minimum length of 1"),
+            Self::LabelTooLong => write!(f, "Label exceeds maximum length of 63 octets"),
+            Self::DisallowedChar(c) => write!(f, "disallowed character {c:?} in label"),
+            Self::LabelStartsWithHyphen => write!(f, "label starts with a hyphen"),
+            Self::LabelEndsWithHyphen => write!(f, "label ends with a hyphen"),
+            Self::InvalidTld => {
+                write!(f, "TLD must be all-alphabetic or a valid xn-- A-label")
+            }
+        }
+    }
+}
+
+impl From<LabelError> for String {
+    fn from(e: LabelError) -> Self {
+        e.to_string()
+    }
+}
+
 #[derive(Clone, Eq, Serialize, Deserialize, Debug)]
 pub struct Label(Vec<u8>);
 
@@ -14,16 +66,33 @@ impl PartialEq<Self> for Label {
     }
 }
 
+/// RFC 4034 §6.1: labels are compared as if all uppercase US-ASCII letters
+/// were lowercased, octet by octet, with each octet treated as unsigned.
+impl PartialOrd for Label {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Label {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0
+            .iter()
+            .map(|b| b.to_ascii_lowercase())
+            .cmp(other.0.iter().map(|b| b.to_ascii_lowercase()))
+    }
+}
+
 impl Label {
-    pub fn from_raw_bytes(bytes: &[u8]) -> Result<Label, String> {
+    pub fn from_raw_bytes(bytes: &[u8]) -> Result<Label, LabelError> {
         // Check for label validity.
         // RFC 2181, Section 11 "Name Syntax".
         // > The length of any one label is limited to between 1 and 63 octets.
         if bytes.is_empty() {
-            return Err("Label requires a minimum length of 1".into());
+            return Err(LabelError::EmptyLabel);
         }
         if bytes.len() > 63 {
-            return Err("Label exceeds maximum length of 63 octets".into());
+            return Err(LabelError::LabelTooLong);
         };
 
         Ok(Self(bytes.to_vec()))
@@ -41,23 +110,68 @@ impl Label {
         &self.0
     }
 
-    pub fn from_ascii(s: &str) -> Result<Self, String> {
+    pub fn from_ascii(s: &str) -> Result<Self, LabelError> {
+        Self::from_ascii_with_profile(s, AllowedAscii::Permissive, false)
+    }
+
+    /// Builds a `Label` from an ASCII string under the given [`AllowedAscii`]
+    /// validation profile. Set `is_tld` when `s` is the rightmost (top-level)
+    /// label of a name: [`AllowedAscii::Rfc1123`] imposes an extra rule on
+    /// the TLD that doesn't apply to other labels, and the permissive
+    /// profile ignores it.
+    pub fn from_ascii_with_profile(
+        s: &str,
+        profile: AllowedAscii,
+        is_tld: bool,
+    ) -> Result<Self, LabelError> {
         if s.len() > 63 {
-            return Err("Label exceeds maximum length of 63 octets".into());
+            return Err(LabelError::LabelTooLong);
         }
 
         if s.as_bytes() == WILDCARD {
             return Ok(Self::wildcard());
         }
 
-        if !s.is_empty()
-            && s.is_ascii()
-            && s.chars().take(1).all(|c| is_safe_ascii(c, true, false))
-            && s.chars().skip(1).all(|c| is_safe_ascii(c, false, false))
-        {
-            Self::from_raw_bytes(s.as_bytes())
-        } else {
-            Err("Malformed Label".into())
+        if s.is_empty() {
+            return Err(LabelError::EmptyLabel);
+        }
+
+        match profile {
+            AllowedAscii::Permissive => {
+                if s.is_ascii()
+                    && s.chars().take(1).all(|c| is_safe_ascii(c, true, false))
+                    && s.chars().skip(1).all(|c| is_safe_ascii(c, false, false))
+                {
+                    Self::from_raw_bytes(s.as_bytes())
+                } else {
+                    let bad_char = s
+                        .chars()
+                        .enumerate()
+                        .find(|&(i, c)| !is_safe_ascii(c, i == 0, false))
+                        .map(|(_, c)| c)
+                        .unwrap_or('\0');
+                    Err(LabelError::DisallowedChar(bad_char))
+                }
+            }
+            AllowedAscii::Rfc1123 => {
+                if s.starts_with('-') {
+                    return Err(LabelError::LabelStartsWithHyphen);
+                }
+                if s.ends_with('-') {
+                    return Err(LabelError::LabelEndsWithHyphen);
+                }
+                if let Some(c) = s
+                    .chars()
+                    .find(|c| !(c.is_ascii_alphanumeric() || *c == '-'))
+                {
+                    return Err(LabelError::DisallowedChar(c));
+                }
+                if is_tld && !(s.chars().all(|c| c.is_ascii_alphabetic()) || is_xn_label(s)) {
+                    return Err(LabelError::InvalidTld);
+                }
+
+                Self::from_raw_bytes(s.as_bytes())
+            }
         }
     }
 
@@ -66,6 +180,43 @@ impl Label {
         Self(Vec::from(WILDCARD))
     }
 
+    /// Builds a `Label` from a UTF-8 string, running the IDNA ToASCII
+    /// transform when `s` isn't already plain ASCII so that Unicode domain
+    /// labels round-trip through the `xn--` A-label form used on the wire:
+    /// Unicode NFC normalization, UTS#46 mapping (lowercasing/mapping,
+    /// rejecting Disallowed code points), then Punycode-encoding the result
+    /// and prefixing it with `xn--`. The 63-octet label limit is enforced by
+    /// [`Self::from_ascii`] on the encoded result, same as any other label.
+    pub fn from_unicode(s: &str) -> Result<Self, String> {
+        if s.is_ascii() {
+            return Self::from_ascii(s).map_err(Into::into);
+        }
+
+        let ascii = idna::Config::default()
+            .use_std3_ascii_rules(true)
+            .verify_dns_length(true)
+            .check_hyphens(true)
+            .to_ascii(s)
+            .map_err(|errors| format!("IDNA ToASCII failed: {errors}"))?;
+
+        Self::from_ascii(&ascii).map_err(Into::into)
+    }
+
+    /// Decodes this label back to Unicode for display, reversing the IDNA
+    /// ToASCII transform performed by [`Self::from_unicode`]. Labels that aren't
+    /// `xn--` A-labels (including ones that merely happen to contain
+    /// non-Punycode-decodable bytes) are returned unchanged.
+    pub fn to_utf8(&self) -> String {
+        let ascii = String::from_utf8_lossy(&self.0);
+
+        match ascii.strip_prefix("xn--") {
+            Some(encoded) => {
+                idna::punycode::decode_to_string(encoded).unwrap_or_else(|| ascii.into_owned())
+            }
+            None => ascii.into_owned(),
+        }
+    }
+
     pub fn write_ascii<W: Write>(&self, f: &mut W) -> Result<(), fmt::Error> {
         // We can't guarantee that the same input will always translate to the same output
         fn escape_non_ascii<W: Write>(
@@ -117,6 +268,16 @@ impl Hash for Label {
     }
 }
 
+/// Returns true if `s` is a syntactically well-formed `xn--` IDNA A-label,
+/// i.e. its Punycode suffix actually decodes. Used by the
+/// [`AllowedAscii::Rfc1123`] TLD check to accept internationalized TLDs
+/// alongside all-alphabetic ones.
+fn is_xn_label(s: &str) -> bool {
+    s.len() > 4
+        && s[..4].eq_ignore_ascii_case("xn--")
+        && idna::punycode::decode_to_string(&s[4..]).is_some()
+}
+
 fn is_safe_ascii(c: char, is_first: bool, for_encoding: bool) -> bool {
     match c {
         c if !c.is_ascii() => false,
@@ -1,11 +1,24 @@
-use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
-use std::{
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    cmp::Ordering,
     fmt::{self, Write},
     hash::{Hash, Hasher},
     str::FromStr,
 };
 
-use crate::{rr::domain::label::Label, serialize::binary::BinEncoder};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    rr::domain::label::Label,
+    serialize::binary::{BinDecodable, BinDecoder, BinEncoder},
+};
+
+/// Bounds the number of compression-pointer hops followed while decoding a
+/// single name, guarding against pathological or malicious input.
+const MAX_POINTER_HOPS: usize = 128;
 
 #[derive(Clone, Default, Eq, PartialEq, Debug)]
 pub struct Name {
@@ -14,6 +27,26 @@ pub struct Name {
     pub label_ends: Vec<u8>, // 32 Length
 }
 
+/// The result of comparing two [`Name`]s in RFC 4034 §6.1 canonical order
+/// (labels compared case-insensitively, root label first). Distinguishes
+/// the ordinary less/greater/equal cases from one name being a strict,
+/// root-anchored prefix of the other — i.e. one is an ancestor zone of the
+/// other — since an NSEC/NSEC3 gap proof needs to tell "strictly between
+/// two owner names" apart from "is enclosed by one of them".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NameComparison {
+    Less,
+    Greater,
+    Equal,
+    /// `self` has fewer labels than `other`, and every one of `self`'s
+    /// labels matches `other`'s corresponding label read from the root
+    /// (e.g. `example.com.` compared against `a.example.com.`).
+    Shorter,
+    /// The reverse of [`Self::Shorter`]: `other` is a root-anchored prefix
+    /// of `self`.
+    Longer,
+}
+
 #[derive(Eq, Debug, Clone, PartialEq, Serialize, Deserialize)]
 enum ParseState {
     Label,
@@ -150,6 +183,43 @@ impl Name {
         }
     }
 
+    /// Compares `self` and `other` in RFC 4034 §6.1 canonical order: labels
+    /// are compared case-insensitively (see [`Label`]'s `Ord` impl) starting
+    /// from the root, i.e. the rightmost label first. See [`NameComparison`]
+    /// for how a strict ancestor relationship is distinguished from an
+    /// ordinary less/greater ordering.
+    pub fn canonical_cmp(&self, other: &Self) -> NameComparison {
+        // Compares raw label bytes directly (the same case-insensitive,
+        // unsigned-octet rule as `Label`'s `Ord` impl) rather than
+        // round-tripping each label through `Label::from_raw_bytes`, which
+        // can fail validation (e.g. a zero-length label) and has no good
+        // fallback short of panicking.
+        fn label_cmp(a: &[u8], b: &[u8]) -> Ordering {
+            a.iter()
+                .map(|b| b.to_ascii_lowercase())
+                .cmp(b.iter().map(|b| b.to_ascii_lowercase()))
+        }
+
+        let mut a: Vec<&[u8]> = self.iter().collect();
+        let mut b: Vec<&[u8]> = other.iter().collect();
+        a.reverse();
+        b.reverse();
+
+        for (x, y) in a.iter().zip(b.iter()) {
+            match label_cmp(x, y) {
+                Ordering::Equal => continue,
+                Ordering::Less => return NameComparison::Less,
+                Ordering::Greater => return NameComparison::Greater,
+            }
+        }
+
+        match a.len().cmp(&b.len()) {
+            Ordering::Equal => NameComparison::Equal,
+            Ordering::Less => NameComparison::Shorter,
+            Ordering::Greater => NameComparison::Longer,
+        }
+    }
+
     pub fn trim_to(&self, num_labels: usize) -> Self {
         if num_labels > self.label_ends.len() {
             self.clone()
@@ -371,7 +441,7 @@ trait LabelEnc {
 struct LabelEncAscii;
 impl LabelEnc for LabelEncAscii {
     fn to_label(name: &str) -> Result<Label, String> {
-        Label::from_ascii(name)
+        Label::from_ascii(name).map_err(Into::into)
     }
     fn write_label<W: Write>(f: &mut W, label: &Label) -> Result<(), fmt::Error> {
         label.write_ascii(f)
@@ -409,3 +479,112 @@ impl fmt::Display for Name {
         self.write_labels::<fmt::Formatter<'_>, LabelEncAscii>(f)
     }
 }
+
+impl BinDecodable for Name {
+    /// Reads a name from wire-format bytes, following compression pointers
+    /// (RFC 1035 §4.1.4). Each pointer must point strictly backwards in the
+    /// message so that decoding always terminates.
+    fn read(decoder: &mut BinDecoder<'_>) -> Result<Self, String> {
+        Self::read_with_hops(decoder, 0)
+    }
+}
+
+impl Name {
+    /// Body of [`Name::read`]; `hops` counts the pointers already followed
+    /// for this name, so [`MAX_POINTER_HOPS`] applies across the whole chain.
+    fn read_with_hops(decoder: &mut BinDecoder<'_>, mut hops: usize) -> Result<Self, String> {
+        let mut name = Self::new();
+
+        loop {
+            let len = decoder.peek_u8()?;
+
+            match len & 0xC0 {
+                0x00 => {
+                    decoder.read_u8()?;
+                    if len == 0 {
+                        break;
+                    }
+
+                    let label = decoder.read_vec(len as usize)?;
+                    name = name.append_label(Label::from_raw_bytes(&label)?)?;
+                }
+                0xC0 => {
+                    let pointer_location = decoder.index();
+                    hops += 1;
+                    if hops > MAX_POINTER_HOPS {
+                        return Err("too many compression pointer hops".into());
+                    }
+
+                    let hi = decoder.read_u8()? & 0x3F;
+                    let lo = decoder.read_u8()?;
+                    let pointer = (hi as usize) << 8 | lo as usize;
+
+                    if pointer >= pointer_location {
+                        return Err(
+                            "compression pointer does not point backwards in the message".into(),
+                        );
+                    }
+
+                    let mut target = decoder.clone_from(pointer)?;
+                    name = name.append_name(&Self::read_with_hops(&mut target, hops)?)?;
+                    break;
+                }
+                _ => return Err("unsupported DNS label length prefix".into()),
+            }
+        }
+
+        name.set_fqdn(true);
+        Ok(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serialize::binary::BinDecoder;
+
+    #[test]
+    fn read_uncompressed_name() {
+        // 3www7example3com0
+        let wire = b"\x03www\x07example\x03com\x00";
+        let mut decoder = BinDecoder::new(wire);
+        let name = Name::read(&mut decoder).unwrap();
+
+        assert_eq!(name, Name::from_ascii("www.example.com.").unwrap());
+    }
+
+    #[test]
+    fn read_name_follows_compression_pointer() {
+        // "example.com." at offset 0, then "www" at offset 13 pointing back
+        // to offset 0 to spell "www.example.com.".
+        let mut wire = b"\x07example\x03com\x00".to_vec();
+        let pointer_target = wire.len();
+        wire.extend_from_slice(b"\x03www\xc0\x00");
+
+        let mut decoder = BinDecoder::new(&wire);
+        decoder.set_index(pointer_target).unwrap();
+        let name = Name::read(&mut decoder).unwrap();
+
+        assert_eq!(name, Name::from_ascii("www.example.com.").unwrap());
+    }
+
+    #[test]
+    fn read_name_rejects_forward_pointing_pointer() {
+        // A pointer must point strictly backwards; this one points forward
+        // to its own following byte.
+        let wire = b"\xc0\x02\x00".to_vec();
+        let mut decoder = BinDecoder::new(&wire);
+
+        assert!(Name::read(&mut decoder).is_err());
+    }
+
+    #[test]
+    fn read_name_rejects_self_pointing_pointer() {
+        // A pointer pointing at itself would loop forever without the
+        // strictly-backwards check.
+        let wire = b"\xc0\x00".to_vec();
+        let mut decoder = BinDecoder::new(&wire);
+
+        assert!(Name::read(&mut decoder).is_err());
+    }
+}
@@ -0,0 +1,8 @@
+pub mod dns_class;
+pub mod dnssec;
+pub mod domain;
+pub mod rdata;
+pub mod record_data;
+pub mod record_type;
+pub mod resource;
+pub mod rrset;
@@ -0,0 +1,37 @@
+use core::net::Ipv4Addr;
+
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+use crate::serialize::binary::{BinDecoder, BinEncodable, BinEncoder};
+
+/// [RFC 1035](https://tools.ietf.org/html/rfc1035#section-3.4.1), DOMAIN NAMES - IMPLEMENTATION AND SPECIFICATION, November 1987
+///
+/// ```text
+/// 3.4.1. A RDATA format
+///
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///     |                    ADDRESS                   |
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///
+/// ADDRESS         A 32 bit Internet address.
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub struct A(pub Ipv4Addr);
+
+impl A {
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, rdata_length: usize) -> Result<Self, String> {
+        if rdata_length != 4 {
+            return Err("A RDATA must be exactly 4 octets".into());
+        }
+
+        let octets = decoder.read_slice(4)?;
+        Ok(Self(Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3])))
+    }
+}
+
+impl BinEncodable for A {
+    fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
+        encoder.emit_vec(&self.0.octets())
+    }
+}
@@ -0,0 +1,36 @@
+use core::net::Ipv6Addr;
+
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+use crate::serialize::binary::{BinDecoder, BinEncodable, BinEncoder};
+
+/// [RFC 3596](https://tools.ietf.org/html/rfc3596#section-2.2), DNS Extensions to Support IPv6, October 2003
+///
+/// ```text
+/// 2.2 AAAA data format
+///
+///    A 128 bit IPv6 address is encoded in the data portion of an AAAA
+///    resource record in network byte order (high-order byte first).
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub struct AAAA(pub Ipv6Addr);
+
+impl AAAA {
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, rdata_length: usize) -> Result<Self, String> {
+        if rdata_length != 16 {
+            return Err("AAAA RDATA must be exactly 16 octets".into());
+        }
+
+        let octets = decoder.read_slice(16)?;
+        let mut addr = [0u8; 16];
+        addr.copy_from_slice(octets);
+        Ok(Self(Ipv6Addr::from(addr)))
+    }
+}
+
+impl BinEncodable for AAAA {
+    fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
+        encoder.emit_vec(&self.0.octets())
+    }
+}
@@ -0,0 +1,36 @@
+use alloc::string::String;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    rr::domain::name::Name,
+    serialize::binary::{BinDecodable, BinDecoder, BinEncodable, BinEncoder},
+};
+
+/// [RFC 1035](https://tools.ietf.org/html/rfc1035#section-3.3.1), DOMAIN NAMES - IMPLEMENTATION AND SPECIFICATION, November 1987
+///
+/// ```text
+/// 3.3.1. CNAME RDATA format
+///
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///     /                     CNAME                     /
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///
+/// CNAME           A <domain-name> which specifies the canonical or primary
+///                 name for the owner. The owner name is an alias.
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct CNAME(pub Name);
+
+impl CNAME {
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, _rdata_length: usize) -> Result<Self, String> {
+        Name::read(decoder).map(Self)
+    }
+}
+
+impl BinEncodable for CNAME {
+    fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
+        let is_canonical_names = encoder.is_canonical_names();
+        self.0.emit_with_lowercase(encoder, is_canonical_names)
+    }
+}
@@ -0,0 +1,35 @@
+use alloc::string::String;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    rr::dnssec::rdata::dns_key::DNSKEY as DnssecDNSKEY,
+    serialize::binary::{BinDecoder, BinEncodable, BinEncoder},
+};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct DNSKEY(DnssecDNSKEY);
+
+impl DNSKEY {
+    /// Wraps an already-built [`DnssecDNSKEY`], for callers outside this
+    /// crate that construct a DNSKEY RRset from a source other than the
+    /// wire format (e.g. a host-side DNS resolver library's own typed
+    /// records) and need an [`RData::DNSKEY`](crate::rr::record_data::RData::DNSKEY) to put it in.
+    pub fn new(dnskey: DnssecDNSKEY) -> Self {
+        Self(dnskey)
+    }
+
+    pub fn dnskey(&self) -> &DnssecDNSKEY {
+        &self.0
+    }
+
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, rdata_length: usize) -> Result<Self, String> {
+        DnssecDNSKEY::read(decoder, rdata_length).map(Self)
+    }
+}
+
+impl BinEncodable for DNSKEY {
+    fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
+        self.0.emit(encoder)
+    }
+}
@@ -0,0 +1,35 @@
+use alloc::string::String;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    rr::dnssec::rdata::ds::DS as DnssecDS,
+    serialize::binary::{BinDecoder, BinEncodable, BinEncoder},
+};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize, Serialize)]
+pub struct DS(DnssecDS);
+
+impl DS {
+    /// Wraps an already-built [`DnssecDS`], for callers outside this crate
+    /// that construct a DS RRset from a source other than the wire format
+    /// (e.g. a host-side DNS resolver library's own typed records) and need
+    /// an [`RData::DS`](crate::rr::record_data::RData::DS) to put it in.
+    pub fn new(ds: DnssecDS) -> Self {
+        Self(ds)
+    }
+
+    pub fn ds(&self) -> &DnssecDS {
+        &self.0
+    }
+
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, rdata_length: usize) -> Result<Self, String> {
+        DnssecDS::read(decoder, rdata_length).map(Self)
+    }
+}
+
+impl BinEncodable for DS {
+    fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
+        self.0.emit(encoder)
+    }
+}
@@ -0,0 +1,12 @@
+pub mod a;
+pub mod aaaa;
+pub mod cname;
+pub mod dns_key;
+pub mod ds;
+pub mod mx;
+pub mod ns;
+pub mod nsec;
+pub mod nsec3;
+pub mod rrsig;
+pub mod soa;
+pub mod txt;
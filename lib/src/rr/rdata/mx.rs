@@ -0,0 +1,59 @@
+use alloc::string::String;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    rr::domain::name::Name,
+    serialize::binary::{BinDecodable, BinDecoder, BinEncodable, BinEncoder},
+};
+
+/// [RFC 1035](https://tools.ietf.org/html/rfc1035#section-3.3.9), DOMAIN NAMES - IMPLEMENTATION AND SPECIFICATION, November 1987
+///
+/// ```text
+/// 3.3.9. MX RDATA format
+///
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///     |                  PREFERENCE                   |
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///     /                   EXCHANGE                    /
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///
+/// PREFERENCE      A 16 bit integer which specifies the preference given to
+///                 this RR among others at the same owner. Lower values
+///                 are preferred.
+///
+/// EXCHANGE        A <domain-name> which specifies a host willing to act as
+///                 a mail exchange for the owner name.
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct MX {
+    preference: u16,
+    exchange: Name,
+}
+
+impl MX {
+    pub fn preference(&self) -> u16 {
+        self.preference
+    }
+
+    pub fn exchange(&self) -> &Name {
+        &self.exchange
+    }
+}
+
+impl MX {
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, _rdata_length: usize) -> Result<Self, String> {
+        let preference = decoder.read_u16()?;
+        let exchange = Name::read(decoder)?;
+        Ok(Self { preference, exchange })
+    }
+}
+
+impl BinEncodable for MX {
+    fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
+        encoder.emit_u16(self.preference())?;
+
+        let is_canonical_names = encoder.is_canonical_names();
+        self.exchange().emit_with_lowercase(encoder, is_canonical_names)
+    }
+}
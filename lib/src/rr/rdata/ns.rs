@@ -0,0 +1,36 @@
+use alloc::string::String;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    rr::domain::name::Name,
+    serialize::binary::{BinDecodable, BinDecoder, BinEncodable, BinEncoder},
+};
+
+/// [RFC 1035](https://tools.ietf.org/html/rfc1035#section-3.3.11), DOMAIN NAMES - IMPLEMENTATION AND SPECIFICATION, November 1987
+///
+/// ```text
+/// 3.3.11. NS RDATA format
+///
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///     /                   NSDNAME                    /
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///
+/// NSDNAME         A <domain-name> which specifies a host which should be
+///                 authoritative for the specified class and domain.
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct NS(pub Name);
+
+impl NS {
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, _rdata_length: usize) -> Result<Self, String> {
+        Name::read(decoder).map(Self)
+    }
+}
+
+impl BinEncodable for NS {
+    fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
+        let is_canonical_names = encoder.is_canonical_names();
+        self.0.emit_with_lowercase(encoder, is_canonical_names)
+    }
+}
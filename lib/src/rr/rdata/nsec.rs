@@ -0,0 +1,26 @@
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    rr::dnssec::rdata::nsec::NSEC as DnssecNSEC,
+    serialize::binary::{BinDecoder, BinEncodable, BinEncoder},
+};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize, Serialize)]
+pub struct NSEC(DnssecNSEC);
+
+impl NSEC {
+    pub fn nsec(&self) -> &DnssecNSEC {
+        &self.0
+    }
+
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, rdata_length: usize) -> Result<Self, String> {
+        DnssecNSEC::read(decoder, rdata_length).map(Self)
+    }
+}
+
+impl BinEncodable for NSEC {
+    fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
+        self.0.emit(encoder)
+    }
+}
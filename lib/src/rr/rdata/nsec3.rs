@@ -0,0 +1,26 @@
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    rr::dnssec::rdata::nsec3::NSEC3 as DnssecNSEC3,
+    serialize::binary::{BinDecoder, BinEncodable, BinEncoder},
+};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize, Serialize)]
+pub struct NSEC3(DnssecNSEC3);
+
+impl NSEC3 {
+    pub fn nsec3(&self) -> &DnssecNSEC3 {
+        &self.0
+    }
+
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, rdata_length: usize) -> Result<Self, String> {
+        DnssecNSEC3::read(decoder, rdata_length).map(Self)
+    }
+}
+
+impl BinEncodable for NSEC3 {
+    fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
+        self.0.emit(encoder)
+    }
+}
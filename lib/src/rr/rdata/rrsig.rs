@@ -1,6 +1,25 @@
+use alloc::string::String;
+
 use serde::{Deserialize, Serialize};
 
 use crate::rr::dnssec::rdata::sig::SIG;
+use crate::serialize::binary::{BinDecoder, BinEncodable, BinEncoder};
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Deserialize, Serialize)]
 pub struct RRSIG(SIG);
+
+impl RRSIG {
+    pub fn sig(&self) -> &SIG {
+        &self.0
+    }
+
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, rdata_length: usize) -> Result<Self, String> {
+        SIG::read(decoder, rdata_length).map(Self)
+    }
+}
+
+impl BinEncodable for RRSIG {
+    fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
+        self.0.emit(encoder)
+    }
+}
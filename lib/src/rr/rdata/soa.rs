@@ -0,0 +1,104 @@
+use alloc::string::String;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    rr::domain::name::Name,
+    serialize::binary::{BinDecodable, BinDecoder, BinEncodable, BinEncoder},
+};
+
+/// [RFC 1035](https://tools.ietf.org/html/rfc1035#section-3.3.13), DOMAIN NAMES - IMPLEMENTATION AND SPECIFICATION, November 1987
+///
+/// ```text
+/// 3.3.13. SOA RDATA format
+///
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///     /                     MNAME                     /
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///     /                     RNAME                     /
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///     |                    SERIAL                     |
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///     |                    REFRESH                    |
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///     |                     RETRY                     |
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///     |                    EXPIRE                     |
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+///     |                    MINIMUM                    |
+///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct SOA {
+    mname: Name,
+    rname: Name,
+    serial: u32,
+    refresh: u32,
+    retry: u32,
+    expire: u32,
+    minimum: u32,
+}
+
+impl SOA {
+    pub fn mname(&self) -> &Name {
+        &self.mname
+    }
+
+    pub fn rname(&self) -> &Name {
+        &self.rname
+    }
+
+    pub fn serial(&self) -> u32 {
+        self.serial
+    }
+
+    pub fn refresh(&self) -> u32 {
+        self.refresh
+    }
+
+    pub fn retry(&self) -> u32 {
+        self.retry
+    }
+
+    pub fn expire(&self) -> u32 {
+        self.expire
+    }
+
+    pub fn minimum(&self) -> u32 {
+        self.minimum
+    }
+}
+
+impl SOA {
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, _rdata_length: usize) -> Result<Self, String> {
+        let mname = Name::read(decoder)?;
+        let rname = Name::read(decoder)?;
+        let serial = decoder.read_u32()?;
+        let refresh = decoder.read_u32()?;
+        let retry = decoder.read_u32()?;
+        let expire = decoder.read_u32()?;
+        let minimum = decoder.read_u32()?;
+        Ok(Self {
+            mname,
+            rname,
+            serial,
+            refresh,
+            retry,
+            expire,
+            minimum,
+        })
+    }
+}
+
+impl BinEncodable for SOA {
+    fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
+        let is_canonical_names = encoder.is_canonical_names();
+        self.mname().emit_with_lowercase(encoder, is_canonical_names)?;
+        self.rname().emit_with_lowercase(encoder, is_canonical_names)?;
+        encoder.emit_u32(self.serial())?;
+        encoder.emit_u32(self.refresh())?;
+        encoder.emit_u32(self.retry())?;
+        encoder.emit_u32(self.expire())?;
+        encoder.emit_u32(self.minimum())
+    }
+}
@@ -1,6 +1,11 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+
 use serde::{Deserialize, Serialize};
 
-use crate::{rr::record_data::RData, serialize::binary::{BinEncodable, BinEncoder}};
+use crate::{
+    rr::record_data::RData,
+    serialize::binary::{BinDecoder, BinEncodable, BinEncoder},
+};
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub struct TXT {
@@ -15,6 +20,25 @@ impl TXT {
     pub fn into_rdata(self) -> RData {
         RData::TXT(self)
     }
+
+    /// Reads the `rdata_length` octets of TXT RDATA as a sequence of
+    /// `<character-string>`s.
+    pub(crate) fn read(decoder: &mut BinDecoder<'_>, rdata_length: usize) -> Result<Self, String> {
+        let end = decoder.index() + rdata_length;
+        let mut txt_data = Vec::new();
+
+        while decoder.index() < end {
+            txt_data.push(decoder.read_character_data()?.into_boxed_slice());
+        }
+
+        if decoder.index() != end {
+            return Err("TXT RDATA length did not match the declared record length".into());
+        }
+
+        Ok(Self {
+            txt_data: txt_data.into_boxed_slice(),
+        })
+    }
 }
 
 impl BinEncodable for TXT {
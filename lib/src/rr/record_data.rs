@@ -1,12 +1,115 @@
+use alloc::{format, string::String};
 use serde::{Deserialize, Serialize};
 
-use crate::serialize::binary::{BinEncodable, BinEncoder};
+use crate::rr::record_type::RecordType;
+use crate::serialize::binary::{BinDecoder, BinEncodable, BinEncoder};
 
+use super::rdata::a::A;
+use super::rdata::aaaa::AAAA;
+use super::rdata::cname::CNAME;
+use super::rdata::dns_key::DNSKEY;
+use super::rdata::ds::DS;
+use super::rdata::mx::MX;
+use super::rdata::ns::NS;
+use super::rdata::nsec::NSEC;
+use super::rdata::nsec3::NSEC3;
 use super::rdata::rrsig::RRSIG;
+use super::rdata::soa::SOA;
 use super::rdata::txt::TXT;
 
 #[derive(Debug, PartialEq, Clone, Eq, Deserialize, Serialize)]
 pub enum RData {
+    /// [RFC 1035](https://tools.ietf.org/html/rfc1035#section-3.4.1), DOMAIN NAMES - IMPLEMENTATION AND SPECIFICATION, November 1987
+    ///
+    /// ```text
+    /// 3.4.1. A RDATA format
+    ///
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    ///     |                    ADDRESS                   |
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    ///
+    /// ADDRESS         A 32 bit Internet address.
+    /// ```
+    A(A),
+    /// [RFC 3596](https://tools.ietf.org/html/rfc3596#section-2.2), DNS Extensions to Support IPv6, October 2003
+    ///
+    /// ```text
+    /// 2.2 AAAA data format
+    ///
+    ///    A 128 bit IPv6 address is encoded in the data portion of an AAAA
+    ///    resource record in network byte order (high-order byte first).
+    /// ```
+    AAAA(AAAA),
+    /// [RFC 1035](https://tools.ietf.org/html/rfc1035#section-3.3.11), DOMAIN NAMES - IMPLEMENTATION AND SPECIFICATION, November 1987
+    ///
+    /// ```text
+    /// 3.3.11. NS RDATA format
+    ///
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    ///     /                   NSDNAME                    /
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    ///
+    /// NSDNAME         A <domain-name> which specifies a host which should be
+    ///                 authoritative for the specified class and domain.
+    /// ```
+    NS(NS),
+    /// [RFC 1035](https://tools.ietf.org/html/rfc1035#section-3.3.1), DOMAIN NAMES - IMPLEMENTATION AND SPECIFICATION, November 1987
+    ///
+    /// ```text
+    /// 3.3.1. CNAME RDATA format
+    ///
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    ///     /                     CNAME                     /
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    ///
+    /// CNAME           A <domain-name> which specifies the canonical or primary
+    ///                 name for the owner. The owner name is an alias.
+    /// ```
+    CNAME(CNAME),
+    /// [RFC 1035](https://tools.ietf.org/html/rfc1035#section-3.3.9), DOMAIN NAMES - IMPLEMENTATION AND SPECIFICATION, November 1987
+    ///
+    /// ```text
+    /// 3.3.9. MX RDATA format
+    ///
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    ///     |                  PREFERENCE                   |
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    ///     /                   EXCHANGE                    /
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    /// ```
+    MX(MX),
+    /// [RFC 1035](https://tools.ietf.org/html/rfc1035#section-3.3.13), DOMAIN NAMES - IMPLEMENTATION AND SPECIFICATION, November 1987
+    ///
+    /// ```text
+    /// 3.3.13. SOA RDATA format
+    ///
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    ///     /                     MNAME                     /
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    ///     /                     RNAME                     /
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    ///     |                    SERIAL                     |
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    ///     |                    REFRESH                    |
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    ///     |                     RETRY                     |
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    ///     |                    EXPIRE                     |
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    ///     |                    MINIMUM                    |
+    ///     +--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+--+
+    /// ```
+    SOA(SOA),
+    /// [RFC 4034](https://tools.ietf.org/html/rfc4034#section-2), DNSSEC Resource Records, March 2005
+    ///
+    /// ```text
+    /// 2.1.  DNSKEY RDATA Wire Format
+    ///
+    ///    The RDATA for a DNSKEY RR consists of a 2 octet Flags Field, a 1
+    ///    octet Protocol Field, a 1 octet Algorithm Field, and the Public Key
+    ///    Field.
+    /// ```
+    DNSKEY(DNSKEY),
     /// ```text
     /// 3.3.14. TXT RDATA format
     ///
@@ -55,13 +158,79 @@ pub enum RData {
     ///    +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
     /// ```
     RRSIG(RRSIG),
+    /// [RFC 4034](https://tools.ietf.org/html/rfc4034#section-5), DNSSEC Resource Records, March 2005
+    ///
+    /// ```text
+    /// 5.1.  DS RDATA Wire Format
+    ///
+    ///    The RDATA for a DS RR consists of a 2 octet Key Tag field, a 1
+    ///    octet Algorithm field, a 1 octet Digest Type field, and a Digest
+    ///    field.
+    /// ```
+    DS(DS),
+    /// [RFC 4034](https://tools.ietf.org/html/rfc4034#section-4), DNSSEC Resource Records, March 2005
+    ///
+    /// ```text
+    /// 4.1.  NSEC RDATA Wire Format
+    ///
+    ///    The RDATA of the NSEC RR is the Next Domain Name field followed by
+    ///    the Type Bit Maps field.
+    /// ```
+    NSEC(NSEC),
+    /// [RFC 5155](https://tools.ietf.org/html/rfc5155#section-3), DNSSEC Hashed Authenticated Denial of Existence, March 2008
+    ///
+    /// ```text
+    /// 3.2.  NSEC3 RDATA Wire Format
+    ///
+    ///    The RDATA of the NSEC3 RR is the Hash Algorithm, Flags, Iterations,
+    ///    Salt, Next Hashed Owner Name, and Type Bit Maps fields.
+    /// ```
+    NSEC3(NSEC3),
 }
 
 impl BinEncodable for RData {
     fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
         match *self {
+            Self::A(ref a) => a.emit(encoder),
+            Self::AAAA(ref aaaa) => aaaa.emit(encoder),
+            Self::NS(ref ns) => ns.emit(encoder),
+            Self::CNAME(ref cname) => cname.emit(encoder),
+            Self::MX(ref mx) => mx.emit(encoder),
+            Self::SOA(ref soa) => soa.emit(encoder),
+            Self::DNSKEY(ref dnskey) => dnskey.emit(encoder),
             Self::TXT(ref txt) => txt.emit(encoder), // TODO: Implement
             Self::RRSIG(ref sig) => sig.emit(encoder), // TODO: Implement
+            Self::DS(ref ds) => ds.emit(encoder),
+            Self::NSEC(ref nsec) => nsec.emit(encoder),
+            Self::NSEC3(ref nsec3) => nsec3.emit(encoder),
+        }
+    }
+}
+
+impl RData {
+    /// Reads `rdata_length` octets of RDATA, dispatching on `record_type` to
+    /// the matching variant's wire-format parser.
+    pub(crate) fn read(
+        decoder: &mut BinDecoder<'_>,
+        record_type: RecordType,
+        rdata_length: usize,
+    ) -> Result<Self, String> {
+        match record_type {
+            RecordType::A => A::read(decoder, rdata_length).map(Self::A),
+            RecordType::AAAA => AAAA::read(decoder, rdata_length).map(Self::AAAA),
+            RecordType::NS => NS::read(decoder, rdata_length).map(Self::NS),
+            RecordType::CNAME => CNAME::read(decoder, rdata_length).map(Self::CNAME),
+            RecordType::MX => MX::read(decoder, rdata_length).map(Self::MX),
+            RecordType::SOA => SOA::read(decoder, rdata_length).map(Self::SOA),
+            RecordType::DNSKEY => DNSKEY::read(decoder, rdata_length).map(Self::DNSKEY),
+            RecordType::TXT => TXT::read(decoder, rdata_length).map(Self::TXT),
+            RecordType::RRSIG | RecordType::SIG => {
+                RRSIG::read(decoder, rdata_length).map(Self::RRSIG)
+            }
+            RecordType::DS => DS::read(decoder, rdata_length).map(Self::DS),
+            RecordType::NSEC => NSEC::read(decoder, rdata_length).map(Self::NSEC),
+            RecordType::NSEC3 => NSEC3::read(decoder, rdata_length).map(Self::NSEC3),
+            other => Err(format!("unsupported record type for decoding: {other:?}")),
         }
     }
 }
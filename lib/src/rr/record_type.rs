@@ -1,6 +1,8 @@
+use alloc::string::String;
+
 use serde::{Deserialize, Serialize};
 
-use crate::serialize::binary::{BinEncodable, BinEncoder};
+use crate::serialize::binary::{BinDecodable, BinDecoder, BinEncodable, BinEncoder};
 
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Deserialize, Serialize)]
 pub enum RecordType {
@@ -147,3 +149,9 @@ impl BinEncodable for RecordType {
         encoder.emit_u16((*self).into())
     }
 }
+
+impl BinDecodable for RecordType {
+    fn read(decoder: &mut BinDecoder<'_>) -> Result<Self, String> {
+        decoder.read_u16().map(Self::from)
+    }
+}
@@ -1,9 +1,12 @@
+use alloc::string::String;
+use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
 use crate::rr::dns_class::DNSClass;
 use crate::rr::domain::name::Name;
 use crate::rr::record_data::RData;
 use crate::rr::record_type::RecordType;
+use crate::serialize::binary::{BinDecodable, BinDecoder, BinEncodable, BinEncoder};
 
 #[derive(PartialEq, Eq, Debug, Clone, Deserialize, Serialize)]
 pub struct Record {
@@ -35,3 +38,62 @@ impl Record {
         self.rdata.as_ref()
     }
 }
+
+impl BinDecodable for Record {
+    /// Reads a resource record off the wire: the owner name, type, class,
+    /// TTL, a 16-bit RDATA length, and then the RDATA itself. The declared
+    /// RDATA length is checked against what the type-specific parser
+    /// actually consumed.
+    fn read(decoder: &mut BinDecoder<'_>) -> Result<Self, String> {
+        let name_labels = Name::read(decoder)?;
+        let rr_type = RecordType::read(decoder)?;
+        let dns_class = DNSClass::read(decoder)?;
+        let ttl = decoder.read_u32()?;
+        let rdata_length = decoder.read_u16()? as usize;
+
+        let rdata_start = decoder.index();
+        let rdata = if rdata_length == 0 {
+            None
+        } else {
+            Some(RData::read(decoder, rr_type, rdata_length)?)
+        };
+
+        if decoder.index() - rdata_start != rdata_length {
+            return Err("RDATA length did not match the declared record length".into());
+        }
+
+        Ok(Self {
+            name_labels,
+            rr_type,
+            dns_class,
+            ttl,
+            rdata,
+        })
+    }
+}
+
+impl BinEncodable for Record {
+    /// Writes this record back to the wire layout [`Self::read`] parses:
+    /// owner name, type, class, TTL, a 16-bit RDATA length, then the RDATA.
+    /// In canonical mode (`encoder.is_canonical_names()`) the owner name is
+    /// lowercased and never compressed into a pointer, as RFC 4034 §6.2
+    /// canonical RR form requires.
+    fn emit(&self, encoder: &mut BinEncoder<'_>) -> Result<(), String> {
+        let is_canonical_names = encoder.is_canonical_names();
+        self.name_labels
+            .emit_with_lowercase(encoder, is_canonical_names)?;
+        self.rr_type.emit(encoder)?;
+        self.dns_class.emit(encoder)?;
+        encoder.emit_u32(self.ttl)?;
+
+        let mut rdata_buf = Vec::new();
+        if let Some(rdata) = &self.rdata {
+            let mut rdata_encoder = BinEncoder::new(&mut rdata_buf);
+            rdata_encoder.set_canonical_names(is_canonical_names);
+            rdata.emit(&mut rdata_encoder)?;
+        }
+
+        encoder.emit_u16(rdata_buf.len() as u16)?;
+        encoder.emit_vec(&rdata_buf)
+    }
+}
@@ -0,0 +1,96 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::rr::dns_class::DNSClass;
+use crate::rr::domain::name::Name;
+use crate::rr::record_type::RecordType;
+use crate::rr::resource::Record;
+use crate::serialize::binary::{BinEncodable, BinEncoder};
+
+/// A validated resource record set: every member [`Record`] shares the same
+/// owner name, record type, and class, as RFC 4034 §6.3 requires of the set
+/// of records an RRSIG covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RRset {
+    name: Name,
+    record_type: RecordType,
+    dns_class: DNSClass,
+    records: Vec<Record>,
+}
+
+impl RRset {
+    /// Builds an `RRset` from `records`, validating that every member
+    /// shares the first record's owner name, record type, and class. Fails
+    /// if `records` is empty or the invariant doesn't hold.
+    pub fn new(records: Vec<Record>) -> Result<Self, String> {
+        let first = records
+            .first()
+            .ok_or("RRset requires at least one record")?;
+        let name = first.name().clone();
+        let record_type = first.record_type();
+        let dns_class = first.dns_class();
+
+        let shares_rrset = records
+            .iter()
+            .all(|r| r.name() == &name && r.record_type() == record_type && r.dns_class() == dns_class);
+        if !shares_rrset {
+            return Err("RRset records must share the same name, type, and class".into());
+        }
+
+        Ok(Self {
+            name,
+            record_type,
+            dns_class,
+            records,
+        })
+    }
+
+    pub fn name(&self) -> &Name {
+        &self.name
+    }
+
+    pub fn record_type(&self) -> RecordType {
+        self.record_type
+    }
+
+    pub fn dns_class(&self) -> DNSClass {
+        self.dns_class
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Returns the ordered, canonicalized RR(i) byte stream that RFC 4034
+    /// §6.3 defines as the record-set portion of an RRSIG's signature
+    /// preimage: each member emitted with its owner name lowercased and
+    /// uncompressed, its TTL replaced by `original_ttl` (the RRSIG's
+    /// Original TTL field, since the wire TTL of a live record can differ
+    /// from what was signed), and the set sorted by the byte-wise order of
+    /// each member's canonical RDATA.
+    pub fn to_canonical_signed_bytes(&self, original_ttl: u32) -> Result<Vec<u8>, String> {
+        let mut canonical_records = self.records.clone();
+        for record in &mut canonical_records {
+            record.ttl = original_ttl;
+        }
+
+        canonical_records.sort_by_cached_key(|record| -> Vec<u8> {
+            let mut rdata_buf = Vec::new();
+            if let Some(rdata) = record.data() {
+                let mut rdata_encoder = BinEncoder::new(&mut rdata_buf);
+                rdata_encoder.set_canonical_names(true);
+                let _ = rdata.emit(&mut rdata_encoder);
+            }
+            rdata_buf
+        });
+
+        let mut buf = Vec::new();
+        let mut encoder = BinEncoder::new(&mut buf);
+        encoder.set_canonical_names(true);
+        for record in &canonical_records {
+            record.emit(&mut encoder)?;
+        }
+        drop(encoder);
+
+        Ok(buf)
+    }
+}
@@ -1,4 +1,5 @@
-use std::io::Write;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 pub trait BinEncodable {
     /// Write the type to the stream
@@ -58,6 +59,27 @@ impl<'a> BinEncoder<'a> {
         self.canonical_names = canonical_names;
     }
 
+    pub fn is_canonical_names(&self) -> bool {
+        self.canonical_names
+    }
+
+    /// Runs `f` with canonical name encoding forced on, restoring the prior
+    /// setting afterwards.
+    pub fn with_canonical_names<F, E>(&mut self, f: F) -> Result<(), E>
+    where
+        F: FnOnce(&mut Self) -> Result<(), E>,
+    {
+        let was_canonical = self.canonical_names;
+        self.canonical_names = true;
+        let result = f(self);
+        self.canonical_names = was_canonical;
+        result
+    }
+
+    pub fn emit_vec(&mut self, data: &[u8]) -> Result<(), String> {
+        self.write_slice(data)
+    }
+
     pub fn emit_character_data<S: AsRef<[u8]>>(&mut self, char_data: S) -> Result<(), String> {
         let char_bytes = char_data.as_ref();
         if char_bytes.len() > 255 {
@@ -125,14 +147,124 @@ impl<'a> BinEncoder<'a> {
     }
 
     fn write_slice(&mut self, data: &[u8]) -> Result<(), String> {
-        self.buffer.write_all(data).unwrap(); // TODO: Error handling
+        self.buffer.extend_from_slice(data);
         self.offset += data.len();
         Ok(())
     }
 
     pub fn emit(&mut self, b: u8) -> Result<(), String> {
-        self.buffer.write_all(&[b]).unwrap(); // TODO: Error handling
+        self.buffer.push(b);
         self.offset += 1;
         Ok(())
     }
 }
+
+pub trait BinDecodable: Sized {
+    /// Read the type from the stream
+    fn read(decoder: &mut BinDecoder<'_>) -> Result<Self, String>;
+
+    /// Parses the type from raw wire-format bytes
+    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let mut decoder = BinDecoder::new(bytes);
+        Self::read(&mut decoder)
+    }
+}
+
+/// A cursor over wire-format DNS message bytes. Every read is bounds-checked
+/// against the underlying buffer and returns `Err` instead of panicking on
+/// malformed or truncated input.
+pub struct BinDecoder<'a> {
+    buffer: &'a [u8],
+    index: usize,
+}
+
+impl<'a> BinDecoder<'a> {
+    pub fn new(buffer: &'a [u8]) -> Self {
+        Self { buffer, index: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Number of bytes left in the underlying buffer, not scoped to any
+    /// particular RDATA.
+    pub fn remaining(&self) -> usize {
+        self.buffer.len() - self.index
+    }
+
+    pub fn set_index(&mut self, index: usize) -> Result<(), String> {
+        if index > self.buffer.len() {
+            return Err("index out of bounds of the buffer".into());
+        }
+
+        self.index = index;
+        Ok(())
+    }
+
+    /// Returns a decoder over the same buffer positioned at `index`, used to
+    /// follow a DNS name compression pointer without disturbing `self`.
+    pub fn clone_from(&self, index: usize) -> Result<BinDecoder<'a>, String> {
+        if index > self.buffer.len() {
+            return Err("compression pointer out of bounds of the buffer".into());
+        }
+
+        Ok(BinDecoder {
+            buffer: self.buffer,
+            index,
+        })
+    }
+
+    pub fn peek_u8(&self) -> Result<u8, String> {
+        self.buffer
+            .get(self.index)
+            .copied()
+            .ok_or_else(|| "unexpected end of buffer".to_string())
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, String> {
+        let b = self.peek_u8()?;
+        self.index += 1;
+        Ok(b)
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, String> {
+        let bytes = self.read_slice(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, String> {
+        let bytes = self.read_slice(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Reads `len` raw bytes, restricted to what remains in the buffer.
+    pub fn read_slice(&mut self, len: usize) -> Result<&'a [u8], String> {
+        if self.index + len > self.buffer.len() {
+            return Err("insufficient bytes remaining in buffer".into());
+        }
+
+        let slice = &self.buffer[self.index..self.index + len];
+        self.index += len;
+        Ok(slice)
+    }
+
+    pub fn read_vec(&mut self, len: usize) -> Result<Vec<u8>, String> {
+        self.read_slice(len).map(<[u8]>::to_vec)
+    }
+
+    /// Reads a length-delimited `<character-string>`: a single length octet
+    /// followed by that many octets of data.
+    pub fn read_character_data(&mut self) -> Result<Vec<u8>, String> {
+        let len = self.read_u8()? as usize;
+        self.read_vec(len)
+    }
+}
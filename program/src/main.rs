@@ -3,20 +3,44 @@ sp1_zkvm::entrypoint!(main);
 
 use alloy_sol_types::SolType;
 use zkdnssec_lib::{
-    rr::{dns_class::DNSClass, dnssec::rdata::sig::SIG, domain::name::Name, resource::Record},
-    verify_rrsig, PublicValuesStruct,
+    rr::{
+        dns_class::DNSClass,
+        dnssec::{chain::root_anchor_digest_hex, chain::ZoneLink, rdata::sig::SIG},
+        domain::name::Name,
+        resource::Record,
+    },
+    verify_chain_and_rrset, PublicValuesStruct,
 };
 
 pub fn main() {
-    let public_key = sp1_zkvm::io::read_vec();
     let name = sp1_zkvm::io::read::<Name>();
     let dns_class = sp1_zkvm::io::read::<DNSClass>();
     let sig = sp1_zkvm::io::read::<SIG>();
     let records = sp1_zkvm::io::read::<Vec<Record>>();
     let signature = sp1_zkvm::io::read_vec();
+    let chain = sp1_zkvm::io::read::<Vec<ZoneLink>>();
 
-    let is_valid = verify_rrsig(public_key, &name, dns_class, &sig, &records, signature);
-    let bytes = PublicValuesStruct::abi_encode(&PublicValuesStruct { is_valid });
+    // The leaf RRset's signer key is no longer taken from untrusted input:
+    // verify_chain_and_rrset draws it from the chain's own validated leaf
+    // zone, so a valid-but-unrelated chain can no longer vouch for a leaf
+    // signed by an arbitrary key.
+    let is_valid =
+        verify_chain_and_rrset(dns_class, &chain, &name, &sig, &records, signature).is_some();
+
+    // Only commit the root anchor digest when the proof actually rooted to
+    // it; otherwise the public values would assert a root of trust for a
+    // proof that didn't establish one.
+    let root_anchor_digest = if is_valid {
+        root_anchor_digest_hex()
+    } else {
+        String::new()
+    };
+
+    let bytes = PublicValuesStruct::abi_encode(&PublicValuesStruct {
+        is_valid,
+        zone_name: name.to_string(),
+        root_anchor_digest,
+    });
 
     sp1_zkvm::io::commit_slice(&bytes);
 }
@@ -75,20 +75,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let mut stdin = SP1Stdin::new();
 
-    // Write Values to stdin
-    // 1. Public Key
-    // 2. Name
-    // 3. DNSClass
-    // 4. RRSIG
-    // 5. Records
-    // 6. Signature
-
-    stdin.write_vec(inputs.pub_key);
+    // Write Values to stdin, in the exact order program/src/main.rs reads
+    // them:
+    // 1. Name
+    // 2. DNSClass
+    // 3. RRSIG
+    // 4. Records
+    // 5. Signature
+    // 6. Chain of trust, from the root anchors down to the domain
+
     stdin.write(&inputs.name);
     stdin.write(&inputs.dns_class);
     stdin.write(&inputs.rrsig);
-    stdin.write(&inputs.record);
+    stdin.write(&inputs.records);
     stdin.write_vec(inputs.signature);
+    stdin.write(&inputs.chain);
 
     if args.execute {
         let (output, report) = client.execute(ZKDNSSEC_ELF, &stdin).run()?;
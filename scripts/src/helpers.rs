@@ -1,6 +1,8 @@
 use hex::encode;
 
+use trust_dns_client::rr::dnssec::Algorithm as TrustAlgorithm;
 use trust_dns_client::rr::rdata::DNSKEY;
+use trust_dns_client::rr::rdata::DS as TrustDS;
 use trust_dns_client::rr::rdata::RRSIG;
 use trust_dns_client::rr::rdata::TXT;
 use trust_dns_client::rr::Record;
@@ -11,9 +13,15 @@ use trust_dns_resolver::Resolver;
 
 use zkdnssec_lib::rr::dns_class::DNSClass as ZKDNSClass;
 use zkdnssec_lib::rr::dnssec::algorithm::Algorithm as ZKAlgorithm;
+use zkdnssec_lib::rr::dnssec::chain::ZoneLink;
+use zkdnssec_lib::rr::dnssec::rdata::dns_key::DNSKEY as ZKDnssecDNSKEY;
+use zkdnssec_lib::rr::dnssec::rdata::ds::DS as ZKDnssecDS;
 use zkdnssec_lib::rr::dnssec::rdata::sig::SIG as ZKSIG;
 use zkdnssec_lib::rr::domain::name::Name as ZKName;
+use zkdnssec_lib::rr::rdata::dns_key::DNSKEY as ZKDNSKEYRdata;
+use zkdnssec_lib::rr::rdata::ds::DS as ZKDSRdata;
 use zkdnssec_lib::rr::rdata::txt::TXT as ZKTXT;
+use zkdnssec_lib::rr::record_data::RData as ZKRData;
 use zkdnssec_lib::rr::record_type::RecordType as ZKRecordType;
 use zkdnssec_lib::rr::resource::Record as ZKRecord;
 
@@ -83,13 +91,176 @@ fn get_dnskey(domain: &str, key_tag: u16) -> Result<DNSKEY, Box<dyn std::error::
     Ok(dns_key.clone())
 }
 
+/// Finds the RRSIG among `name`'s RRSIG RRset that covers `covers`, the same
+/// "match by `type_covered`" lookup [`get_txt_records`] does inline for TXT,
+/// generalized for the per-zone DNSKEY/DS RRSIGs [`fetch_zone_link`] needs.
+fn get_rrsig(
+    resolver: &Resolver,
+    name: &str,
+    covers: RecordType,
+) -> Result<RRSIG, Box<dyn std::error::Error>> {
+    let rrsig_response = resolver.lookup(name, RecordType::RRSIG)?;
+
+    rrsig_response
+        .records()
+        .iter()
+        .find_map(|r| {
+            let data = RRSIG::try_from_rdata(r.data().unwrap().clone()).ok()?;
+            (data.type_covered() == covers).then_some(data)
+        })
+        .ok_or_else(|| format!("no RRSIG covering {:?} found for {}", covers, name).into())
+}
+
+/// Maps a fetched DNSKEY's algorithm onto the subset `zkdnssec_lib` can
+/// prove. Panics on an unsupported algorithm rather than silently signing
+/// with the wrong curve, since `generate_inputs` used to hardcode
+/// `ECDSAP256SHA256` regardless of what the zone actually used.
+fn map_algorithm(algorithm: TrustAlgorithm) -> ZKAlgorithm {
+    match algorithm {
+        TrustAlgorithm::RSASHA256 => ZKAlgorithm::RSASHA256,
+        TrustAlgorithm::ECDSAP256SHA256 => ZKAlgorithm::ECDSAP256SHA256,
+        TrustAlgorithm::ED25519 => ZKAlgorithm::ED25519,
+        other => panic!("unsupported DNSSEC algorithm for zk proof: {:?}", other),
+    }
+}
+
+/// Converts a fetched RRSIG into the `zkdnssec_lib` `SIG` it signs `records`
+/// of type `type_covered` with.
+fn to_zk_sig(rrsig: &RRSIG, type_covered: ZKRecordType) -> ZKSIG {
+    ZKSIG {
+        type_covered,
+        algorithm: map_algorithm(rrsig.algorithm()),
+        num_labels: rrsig.num_labels(),
+        original_ttl: rrsig.original_ttl(),
+        sig_expiration: rrsig.sig_expiration(),
+        sig_inception: rrsig.sig_inception(),
+        key_tag: rrsig.key_tag(),
+        signer_name: ZKName::from_ascii(rrsig.signer_name().to_ascii()).unwrap(),
+        sig: rrsig.sig().to_vec(),
+    }
+}
+
+/// Converts a fetched DNSKEY into the `zkdnssec_lib` `Record` carrying it,
+/// owned by `zone_name`. `public_key` is passed through verbatim (the raw
+/// RFC 3110/SEC1-point wire bytes DNS published); `verify_ecdsa_signature`
+/// already reconstructs the uncompressed-point prefix for a bare 64-byte
+/// ECDSA key, so no conversion is needed here.
+fn to_zk_dnskey_record(zone_name: &ZKName, ttl: u32, dnskey: &DNSKEY) -> ZKRecord {
+    let zk_dnskey = ZKDnssecDNSKEY {
+        zone_key: dnskey.zone_key(),
+        secure_entry_point: dnskey.secure_entry_point(),
+        revoke: dnskey.revoke(),
+        algorithm: map_algorithm(dnskey.algorithm()),
+        public_key: dnskey.public_key().to_vec(),
+    };
+
+    ZKRecord {
+        name_labels: zone_name.clone(),
+        rr_type: ZKRecordType::DNSKEY,
+        dns_class: ZKDNSClass::IN,
+        ttl,
+        rdata: Some(ZKRData::DNSKEY(ZKDNSKEYRdata::new(zk_dnskey))),
+    }
+}
+
+/// Converts a fetched DS into the `zkdnssec_lib` `Record` carrying it, owned
+/// by `zone_name` (the delegated child zone the DS authorizes, matching how
+/// the parent zone publishes it).
+fn to_zk_ds_record(zone_name: &ZKName, ttl: u32, ds: &TrustDS) -> ZKRecord {
+    let zk_ds = ZKDnssecDS {
+        key_tag: ds.key_tag(),
+        algorithm: map_algorithm(ds.algorithm()),
+        digest_type: ds.digest_type().into(),
+        digest: ds.digest().to_vec(),
+    };
+
+    ZKRecord {
+        name_labels: zone_name.clone(),
+        rr_type: ZKRecordType::DS,
+        dns_class: ZKDNSClass::IN,
+        ttl,
+        rdata: Some(ZKRData::DS(ZKDSRdata::new(zk_ds))),
+    }
+}
+
+/// Fetches one [`ZoneLink`] hop: `zone`'s DNSKEY RRset and its self-signing
+/// RRSIG, plus — unless `zone` is the root — the DS RRset the parent
+/// publishes for it and the RRSIG authenticating that DS RRset.
+fn fetch_zone_link(zone: &str, is_root: bool) -> Result<ZoneLink, Box<dyn std::error::Error>> {
+    let resolver = create_resolver()?;
+    let zone_name = ZKName::from_ascii(zone).unwrap();
+
+    let dnskey_response = resolver.lookup(zone, RecordType::DNSKEY)?;
+    let dnskey_rrsig = get_rrsig(&resolver, zone, RecordType::DNSKEY)?;
+
+    let dnskey_records: Vec<ZKRecord> = dnskey_response
+        .records()
+        .iter()
+        .map(|r| {
+            let dnskey = DNSKEY::try_from_rdata(r.data().unwrap().clone()).unwrap();
+            to_zk_dnskey_record(&zone_name, r.ttl(), &dnskey)
+        })
+        .collect();
+
+    let (ds_records, ds_rrsig) = if is_root {
+        (Vec::new(), None)
+    } else {
+        let ds_response = resolver.lookup(zone, RecordType::DS)?;
+        let ds_rrsig = get_rrsig(&resolver, zone, RecordType::DS)?;
+
+        let ds_records: Vec<ZKRecord> = ds_response
+            .records()
+            .iter()
+            .map(|r| {
+                let ds = TrustDS::try_from_rdata(r.data().unwrap().clone()).unwrap();
+                to_zk_ds_record(&zone_name, r.ttl(), &ds)
+            })
+            .collect();
+
+        (ds_records, Some(to_zk_sig(&ds_rrsig, ZKRecordType::DS)))
+    };
+
+    Ok(ZoneLink {
+        zone_name: zone_name.clone(),
+        dnskey_records,
+        dnskey_rrsig: to_zk_sig(&dnskey_rrsig, ZKRecordType::DNSKEY),
+        ds_records,
+        ds_rrsig,
+    })
+}
+
+/// Lists `domain`'s ancestor zones from the root down to `domain` itself,
+/// e.g. `"example.com"` -> `[".", "com.", "example.com."]`. Every ancestor
+/// name, and `domain` itself, is treated as a zone apex: one zone per label,
+/// with no check for where the actual zone cuts are.
+fn zone_ancestors(domain: &str) -> Vec<String> {
+    let labels: Vec<&str> = domain.trim_end_matches('.').split('.').collect();
+    let mut zones = vec![".".to_string()];
+
+    for i in (0..labels.len()).rev() {
+        zones.push(format!("{}.", labels[i..].join(".")));
+    }
+
+    zones
+}
+
+/// Builds the ordered delegation chain from the IANA root anchors down to
+/// `domain`, for [`zkdnssec_lib::verify_chain_and_rrset`] to verify.
+fn build_chain(domain: &str) -> Result<Vec<ZoneLink>, Box<dyn std::error::Error>> {
+    zone_ancestors(domain)
+        .iter()
+        .enumerate()
+        .map(|(i, zone)| fetch_zone_link(zone, i == 0))
+        .collect()
+}
+
 pub struct Inputs {
-    pub pub_key: Vec<u8>,
     pub name: ZKName,
     pub dns_class: ZKDNSClass,
     pub rrsig: ZKSIG,
-    pub record: ZKRecord,
+    pub records: Vec<ZKRecord>,
     pub signature: Vec<u8>,
+    pub chain: Vec<ZoneLink>,
 }
 
 pub fn generate_inputs(domain: &str) -> Result<Inputs, Box<dyn std::error::Error>> {
@@ -105,17 +276,6 @@ pub fn generate_inputs(domain: &str) -> Result<Inputs, Box<dyn std::error::Error
 
     let dns_key = get_dnskey(domain, rrsig.key_tag())?;
 
-    let pub_key = dns_key.public_key();
-
-    let sec1_pubkey = if pub_key.len() == 64 {
-        let mut buf = Vec::with_capacity(65);
-        buf.push(0x04);
-        buf.extend_from_slice(pub_key);
-        buf
-    } else {
-        pub_key.to_vec()
-    };
-
     let signature = rrsig.sig().to_vec();
 
     println!("\n\nDomain: {:?}", domain);
@@ -155,21 +315,7 @@ pub fn generate_inputs(domain: &str) -> Result<Inputs, Box<dyn std::error::Error
 
     let zk_name = ZKName::from_ascii(domain).unwrap();
     let zk_dns_class: ZKDNSClass = ZKDNSClass::IN;
-    let zk_type_covered = ZKRecordType::TXT;
-    let zk_algorithm = ZKAlgorithm::ECDSAP256SHA256;
-    let zk_signer_name = ZKName::from_ascii(rrsig.signer_name().to_ascii()).unwrap();
-
-    let zk_rrsig = ZKSIG {
-        type_covered: zk_type_covered,
-        algorithm: zk_algorithm,
-        num_labels: rrsig.num_labels(),
-        original_ttl: rrsig.original_ttl(),
-        sig_expiration: rrsig.sig_expiration(),
-        sig_inception: rrsig.sig_inception(),
-        key_tag: rrsig.key_tag(),
-        signer_name: zk_signer_name,
-        sig: signature.clone(),
-    };
+    let zk_rrsig = to_zk_sig(&rrsig, ZKRecordType::TXT);
 
     let data: Box<[Box<[u8]>]> = txt_record
         .data()
@@ -191,13 +337,15 @@ pub fn generate_inputs(domain: &str) -> Result<Inputs, Box<dyn std::error::Error
         rdata: Some(zk_rdata.into_rdata()),
     };
 
+    let chain = build_chain(domain)?;
+
     let inputs = Inputs {
-        pub_key: sec1_pubkey,
         name: zk_name,
         dns_class: zk_dns_class,
         rrsig: zk_rrsig,
-        record: zk_record,
+        records: vec![zk_record],
         signature: signature.clone(),
+        chain,
     };
 
     Ok(inputs)